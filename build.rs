@@ -0,0 +1,25 @@
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+	let commit = Command::new("git")
+		.args(["rev-parse", "--short", "HEAD"])
+		.output()
+		.ok()
+		.filter(|output| output.status.success())
+		.and_then(|output| String::from_utf8(output.stdout).ok())
+		.map(|commit| commit.trim().to_string())
+		.unwrap_or_default();
+
+	let built = std::env::var("SOURCE_DATE_EPOCH").ok()
+		.and_then(|epoch| epoch.parse::<u64>().ok())
+		.unwrap_or_else(|| {
+			SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or_default()
+		});
+
+	println!("cargo:rustc-env=TODOTRACKER_GIT_COMMIT={commit}");
+	println!("cargo:rustc-env=TODOTRACKER_BUILD_TIMESTAMP={built}");
+	println!("cargo:rerun-if-changed=.git/HEAD");
+	println!("cargo:rerun-if-changed=.git/refs");
+	println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+}
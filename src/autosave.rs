@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError, TryLockError};
+use std::time::{Duration, Instant};
+
+use crate::locale::tr;
+use crate::output::outln;
+use crate::{confirm, State, STATE_FILE_PATH};
+
+pub const EMERGENCY_FILE_PATH: &str = "state.emergency.ron";
+pub const AUTOSAVE_FILE_PATH: &str = "state.autosave.ron";
+
+const LOCK_TIMEOUT_MILLISECONDS: u64 = 1000;
+
+pub fn on_termination(state: Arc<Mutex<State>>) {
+	let installed = ctrlc::set_handler(move || {
+		let deadline = Instant::now() + Duration::from_millis(LOCK_TIMEOUT_MILLISECONDS);
+
+		let state = loop {
+			match state.try_lock() {
+				Ok(state) => break Some(state),
+				Err(TryLockError::Poisoned(error)) => break Some(error.into_inner()),
+				Err(TryLockError::WouldBlock) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+				Err(TryLockError::WouldBlock) => break None,
+			}
+		};
+
+		match state {
+			Some(state) if has_unsaved_entries(&state) => {
+				if write(&state, EMERGENCY_FILE_PATH) {
+					eprintln!("{}", tr!("emergency-saved", path = EMERGENCY_FILE_PATH));
+					finish(&state);
+				}
+			}
+			Some(state) => finish(&state),
+			None => tracing::warn!("The state was locked by a running command, not writing {EMERGENCY_FILE_PATH}"),
+		}
+
+		std::process::exit(1);
+	});
+
+	if let Err(error) = installed {
+		tracing::warn!("Failed to install the termination handler: {error}");
+	}
+}
+
+pub fn periodically(state: Arc<Mutex<State>>) {
+	let Some(interval) = state.lock().unwrap_or_else(PoisonError::into_inner).config.autosave_interval() else {
+		return;
+	};
+
+	let interval = interval.to_std().unwrap_or_default();
+
+	std::thread::spawn(move || loop {
+		std::thread::sleep(interval);
+
+		let state = state.lock().unwrap_or_else(PoisonError::into_inner);
+
+		if state.exit {
+			break;
+		}
+
+		if has_unsaved_entries(&state) {
+			if write(&state, AUTOSAVE_FILE_PATH) {
+				tracing::debug!("Autosaved {} entries to {AUTOSAVE_FILE_PATH}", state.entries.len());
+			}
+		} else if PathBuf::from(AUTOSAVE_FILE_PATH).exists() {
+			remove(AUTOSAVE_FILE_PATH);
+		}
+	});
+}
+
+pub fn finish(state: &State) {
+	if !state.dry_run && !state.read_only && !state.sandbox && PathBuf::from(AUTOSAVE_FILE_PATH).exists() {
+		remove(AUTOSAVE_FILE_PATH);
+	}
+}
+
+pub fn recover(state: &mut State, path: &str) {
+	if state.dry_run || state.read_only || !PathBuf::from(path).exists() {
+		return;
+	}
+
+	if !is_newer_than_state_file(path) {
+		tracing::info!("{path} is older than {STATE_FILE_PATH}, removing it");
+		remove(path);
+		return;
+	}
+
+	let recovered = std::fs::read_to_string(path)
+		.map_err(|error| error.to_string())
+		.and_then(|data| ron::from_str::<State>(&data).map_err(|error| error.to_string()));
+
+	match recovered {
+		Ok(recovered) => {
+			outln!("{}", tr!("recovery-found", path = path, count = recovered.entries.len()));
+
+			if confirm(&tr!("confirm-recover")) {
+				state.entries = recovered.entries;
+				state.pomodoros = recovered.pomodoros;
+				state.focus = recovered.focus;
+				state.planned = recovered.planned;
+				state.last_id = state.last_id.max(recovered.last_id);
+				state.selection.clear();
+				state.assign_missing_ids();
+				state.modified = true;
+				outln!("{}", tr!("recovery-loaded", count = state.entries.len()));
+			}
+		}
+		Err(error) => {
+			tracing::warn!("Failed to read {path}: {error}");
+			eprintln!("{}", tr!("recovery-unreadable", path = path));
+			return;
+		}
+	}
+
+	remove(path);
+}
+
+fn has_unsaved_entries(state: &State) -> bool {
+	state.modified && !state.dry_run && !state.read_only
+}
+
+fn is_newer_than_state_file(path: &str) -> bool {
+	let modified = |path: &str| std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+
+	match (modified(path), modified(STATE_FILE_PATH)) {
+		(Some(recovered), Some(saved)) => recovered > saved,
+		_ => true,
+	}
+}
+
+fn write(state: &State, path: &str) -> bool {
+	ron::ser::to_string_pretty(state, ron::ser::PrettyConfig::default())
+		.map_err(|error| error.to_string())
+		.and_then(|data| std::fs::write(path, data).map_err(|error| error.to_string()))
+		.map_err(|error| tracing::warn!("Failed to write {path}: {error}"))
+		.is_ok()
+}
+
+fn remove(path: &str) {
+	if let Err(error) = std::fs::remove_file(path) {
+		tracing::warn!("Failed to remove {path}: {error}");
+	}
+}
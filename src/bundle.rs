@@ -0,0 +1,57 @@
+use std::io::{Read, Write};
+
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::history::HISTORY_FILE_PATH;
+use crate::{State, CONFIG_FILE_PATH, STATE_FILE_PATH};
+
+pub const BUNDLE_FILES: [&str; 3] = [STATE_FILE_PATH, CONFIG_FILE_PATH, HISTORY_FILE_PATH];
+
+pub fn write(state: &State, path: &str) -> Result<Vec<&'static str>, String> {
+	let state_data = ron::ser::to_string_pretty(state, ron::ser::PrettyConfig::default())
+		.map_err(|error| error.to_string())?;
+
+	let mut files = vec![(STATE_FILE_PATH, state_data.into_bytes())];
+
+	for name in [CONFIG_FILE_PATH, HISTORY_FILE_PATH] {
+		match std::fs::read(name) {
+			Ok(data) => files.push((name, data)),
+			Err(error) => tracing::debug!("Not bundling {name}: {error}"),
+		}
+	}
+
+	let file = std::fs::File::create(path).map_err(|error| error.to_string())?;
+	let mut archive = ZipWriter::new(file);
+
+	for (name, data) in &files {
+		archive.start_file(*name, SimpleFileOptions::default()).map_err(|error| error.to_string())?;
+		archive.write_all(data).map_err(|error| error.to_string())?;
+	}
+
+	archive.finish().map_err(|error| error.to_string())?;
+
+	Ok(files.into_iter().map(|(name, _)| name).collect())
+}
+
+pub fn read(path: &str) -> Result<Vec<(&'static str, Vec<u8>)>, String> {
+	let file = std::fs::File::open(path).map_err(|error| error.to_string())?;
+	let mut archive = ZipArchive::new(file).map_err(|error| error.to_string())?;
+	let mut files = Vec::new();
+
+	for name in BUNDLE_FILES {
+		let Ok(mut file) = archive.by_name(name) else {
+			continue;
+		};
+
+		let mut data = Vec::new();
+		file.read_to_end(&mut data).map_err(|error| error.to_string())?;
+		files.push((name, data));
+	}
+
+	if !files.iter().any(|(name, _)| *name == STATE_FILE_PATH) {
+		return Err(format!("{path} does not contain {STATE_FILE_PATH}"));
+	}
+
+	Ok(files)
+}
@@ -0,0 +1,139 @@
+use std::str::SplitWhitespace;
+
+use crate::locale::tr;
+use crate::State;
+
+mod clipboard;
+mod entries;
+mod general;
+mod interop;
+mod macros;
+mod schedule;
+mod search;
+mod storage;
+mod tracking;
+
+type Arguments<'a> = SplitWhitespace<'a>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandError {
+	NotFound,
+	InvalidInput,
+	Io,
+	Rejected,
+}
+
+impl CommandError {
+	pub fn exit_code(self) -> i32 {
+		match self {
+			CommandError::NotFound => 1,
+			CommandError::InvalidInput => 2,
+			CommandError::Io => 3,
+			CommandError::Rejected => 4,
+		}
+	}
+}
+
+pub trait CommandHandler {
+	fn key(&self) -> &'static str;
+
+	fn name(&self) -> &'static str;
+
+	fn description(&self) -> String;
+
+	fn usage(&self) -> &'static str {
+		""
+	}
+
+	fn read_only(&self) -> bool {
+		false
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError>;
+}
+
+pub struct CommandRegistry {
+	commands: Vec<Box<dyn CommandHandler>>,
+}
+
+impl CommandRegistry {
+	pub fn new() -> Self {
+		CommandRegistry {
+			commands: Vec::new(),
+		}
+	}
+
+	pub fn builtin() -> Self {
+		let mut registry = CommandRegistry::new();
+
+		registry.register(Box::new(general::Help));
+		registry.register(Box::new(entries::List));
+		registry.register(Box::new(entries::Add));
+		registry.register(Box::new(entries::Remove));
+		registry.register(Box::new(entries::Move));
+		registry.register(Box::new(entries::Swap));
+		registry.register(Box::new(entries::Show));
+		registry.register(Box::new(entries::Edit));
+		registry.register(Box::new(entries::Rename));
+		registry.register(Box::new(entries::Describe));
+		registry.register(Box::new(entries::Replace));
+		registry.register(Box::new(entries::Link));
+		registry.register(Box::new(entries::Estimate));
+		registry.register(Box::new(entries::Assign));
+		registry.register(Box::new(entries::Locate));
+		registry.register(Box::new(entries::Label));
+		registry.register(Box::new(clipboard::CopyEntry));
+		registry.register(Box::new(clipboard::PasteAdd));
+		registry.register(Box::new(tracking::Start));
+		registry.register(Box::new(tracking::Stop));
+		registry.register(Box::new(tracking::Timesheet));
+		registry.register(Box::new(tracking::Pomodoro));
+		registry.register(Box::new(entries::Focus));
+		registry.register(Box::new(entries::Unfocus));
+		registry.register(Box::new(entries::Plan));
+		registry.register(Box::new(entries::Pick));
+		registry.register(Box::new(entries::Count));
+		registry.register(Box::new(search::Find));
+		registry.register(Box::new(entries::Summary));
+		registry.register(Box::new(entries::Status));
+		registry.register(Box::new(interop::Serve));
+		registry.register(Box::new(interop::Ctl));
+		registry.register(Box::new(interop::Mcp));
+		registry.register(Box::new(interop::Import));
+		registry.register(Box::new(interop::Export));
+		registry.register(Box::new(interop::Vault));
+		registry.register(Box::new(interop::Script));
+		registry.register(Box::new(entries::Clear));
+		registry.register(Box::new(storage::Save));
+		registry.register(Box::new(storage::Load));
+		registry.register(Box::new(general::Capabilities));
+		registry.register(Box::new(general::Version));
+		registry.register(Box::new(general::History));
+		registry.register(Box::new(general::Tutorial));
+		registry.register(Box::new(general::Docs));
+		registry.register(Box::new(macros::Macro));
+		registry.register(Box::new(schedule::Schedule));
+		registry.register(Box::new(general::Exit));
+
+		registry
+	}
+
+	pub fn register(&mut self, command: Box<dyn CommandHandler>) {
+		if self.find(command.key()).is_some() {
+			eprintln!("{}", tr!("command-already-registered", command = command.key()));
+			return;
+		}
+
+		self.commands.push(command);
+	}
+
+	pub fn find(&self, key: &str) -> Option<&dyn CommandHandler> {
+		self.commands.iter()
+			.find(|command| command.key().eq_ignore_ascii_case(key))
+			.map(AsRef::as_ref)
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &dyn CommandHandler> {
+		self.commands.iter().map(AsRef::as_ref)
+	}
+}
@@ -0,0 +1,99 @@
+use arboard::Clipboard;
+
+use super::{Arguments, CommandError, CommandHandler};
+use crate::locale::tr;
+use crate::output::outln;
+use crate::{argument_or_prompt, State, TodoEntry};
+
+pub struct CopyEntry;
+pub struct PasteAdd;
+
+impl CommandHandler for CopyEntry {
+	fn key(&self) -> &'static str {
+		"copy"
+	}
+
+	fn name(&self) -> &'static str {
+		"Copy"
+	}
+
+	fn description(&self) -> String {
+		tr!("copy-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index>"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(&argument_or_prompt(arguments.next(), &tr!("prompt-copy-index")))?;
+
+		let Some(entry) = state.entries.get(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		let text = if entry.description.is_empty() {
+			entry.name.clone()
+		} else {
+			format!("{}\n\n{}", entry.name, entry.description)
+		};
+
+		clipboard()?.set_text(text).map_err(|error| {
+			tracing::warn!("Failed to write to the clipboard: {error}");
+			eprintln!("{}", tr!("clipboard-failed"));
+			CommandError::Io
+		})?;
+
+		outln!("{}", tr!("entry-copied", name = entry.name.as_str()));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for PasteAdd {
+	fn key(&self) -> &'static str {
+		"paste-add"
+	}
+
+	fn name(&self) -> &'static str {
+		"Paste Add"
+	}
+
+	fn description(&self) -> String {
+		tr!("paste-add-description")
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		let text = clipboard()?.get_text().map_err(|error| {
+			tracing::warn!("Failed to read the clipboard: {error}");
+			eprintln!("{}", tr!("clipboard-no-text"));
+			CommandError::NotFound
+		})?;
+
+		let text = text.trim();
+		let (name, description) = text.split_once('\n').unwrap_or((text, ""));
+
+		if name.trim().is_empty() {
+			eprintln!("{}", tr!("clipboard-no-text"));
+			return Err(CommandError::NotFound);
+		}
+
+		outln!("{}", tr!("entry-added", name = name.trim()));
+		state.add_entry(TodoEntry::new(name.trim().to_string(), description.trim().to_string()));
+
+		Ok(())
+	}
+}
+
+fn clipboard() -> Result<Clipboard, CommandError> {
+	Clipboard::new().map_err(|error| {
+		tracing::warn!("Failed to open the clipboard: {error}");
+		eprintln!("{}", tr!("clipboard-failed"));
+		CommandError::Io
+	})
+}
@@ -0,0 +1,1475 @@
+use std::collections::VecDeque;
+
+use rand::Rng;
+use regex::Regex;
+
+use super::{Arguments, CommandError, CommandHandler};
+use crate::filter::Filter;
+use crate::locale::{self, tr};
+use crate::output::{out, outln};
+use crate::{
+	argument_or_prompt, confirm, format_duration, index_or_pick, parse_duration, parse_index, write_backup_file, Color,
+	DuplicatePolicy, EntryLink, State, TodoEntry, DEFAULT_FOCUS_COUNT, DEFAULT_LINK_RELATION,
+};
+
+pub struct List;
+pub struct Add;
+pub struct Remove;
+pub struct Move;
+pub struct Swap;
+pub struct Show;
+pub struct Edit;
+pub struct Rename;
+pub struct Describe;
+pub struct Replace;
+pub struct Link;
+pub struct Estimate;
+pub struct Assign;
+pub struct Locate;
+pub struct Label;
+pub struct Focus;
+pub struct Unfocus;
+pub struct Plan;
+pub struct Pick;
+pub struct Count;
+pub struct Summary;
+pub struct Status;
+pub struct Clear;
+
+impl CommandHandler for List {
+	fn key(&self) -> &'static str {
+		"list"
+	}
+
+	fn name(&self) -> &'static str {
+		"List"
+	}
+
+	fn description(&self) -> String {
+		tr!("list-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[@<name>] [--assignee <name>] [--location <place>] [--by-location]"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		if state.entries.is_empty() {
+			outln!("{}", tr!("nothing-to-list"));
+			return Ok(());
+		}
+
+		let arguments = arguments.collect::<Vec<&str>>();
+		let smart_list = arguments.iter().find_map(|argument| argument.strip_prefix('@'));
+		let filter = match smart_list.map(|name| state.config.smart_list(name)) {
+			Some(Some(Ok(filter))) => Some(filter),
+			Some(Some(Err(error))) => {
+				eprintln!("{error}");
+				return Err(CommandError::InvalidInput);
+			}
+			Some(None) => {
+				eprintln!(
+					"{}",
+					tr!(
+						"smart-list-unknown",
+						name = smart_list.unwrap_or_default(),
+						available = state.config.smart_lists.keys().cloned().collect::<Vec<String>>().join(", ")
+					)
+				);
+				return Err(CommandError::NotFound);
+			}
+			None => None,
+		};
+
+		let assignee = option_value(&arguments, "--assignee");
+		let location = option_value(&arguments, "--location");
+		let by_location = arguments.contains(&"--by-location");
+		let focused = state.focused();
+		let focus_mode = focused.is_some();
+		let shown = focused.unwrap_or_else(|| state.entries.iter().enumerate().collect());
+		let hidden = state.entries.len() - shown.len();
+		let mut entries = shown.into_iter()
+			.filter(|(_, entry)| filter.as_ref().is_none_or(|filter| filter.matches(entry)))
+			.filter(|(_, entry)| assignee.as_deref().is_none_or(|assignee| entry.assigned_to(assignee)))
+			.filter(|(_, entry)| location.as_deref().is_none_or(|location| entry.located_at(location)))
+			.collect::<Vec<(usize, &TodoEntry)>>();
+
+		if by_location {
+			entries.sort_by_key(|(_, entry)| (entry.location.is_none(), entry.location.as_deref().map(str::to_lowercase)));
+		}
+
+		let mut group = None;
+
+		for (index, entry) in &entries {
+			let entry_group = entry.location.as_deref().map(str::to_lowercase);
+
+			if by_location && group.as_ref() != Some(&entry_group) {
+				outln!("{}", entry.location.as_deref().map_or_else(|| tr!("list-no-location"), |location| format!("{location}:")));
+				group = Some(entry_group);
+			}
+
+			let name = entry.color.map_or_else(|| entry.name.clone(), |color| color.paint(&entry.name));
+			let mut line = format!("{index} - {name}: {}", entry.description);
+
+			if let Some(assignee) = &entry.assignee {
+				line.push_str(&format!(" @{assignee}"));
+			}
+
+			if let Some(location) = entry.location.as_ref().filter(|_| !by_location) {
+				line.push_str(&format!(" [{location}]"));
+			}
+
+			outln!("{line}");
+		}
+
+		if let Some(assignee) = assignee.filter(|_| entries.is_empty()) {
+			outln!("{}", tr!("nothing-assigned", name = assignee.as_str()));
+		}
+
+		if let Some(location) = location.filter(|_| entries.is_empty()) {
+			outln!("{}", tr!("nothing-at-location", location = location.as_str()));
+		}
+
+		if hidden > 0 {
+			outln!("{}", tr!("focus-hidden", count = hidden));
+		}
+
+		let estimated = entries.iter()
+			.filter_map(|(_, entry)| entry.estimate())
+			.sum::<chrono::Duration>();
+
+		if estimated.is_zero() {
+			return Ok(());
+		}
+
+		outln!("{}", tr!("list-estimated", duration = format_duration(estimated)));
+
+		if let Some(capacity) = state.config.daily_capacity().filter(|capacity| focus_mode && estimated > *capacity) {
+			eprintln!("{}", tr!("over-capacity", capacity = format_duration(capacity)));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Add {
+	fn key(&self) -> &'static str {
+		"add"
+	}
+
+	fn name(&self) -> &'static str {
+		"Add"
+	}
+
+	fn description(&self) -> String {
+		tr!("add-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[name]"
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		let name = arguments.collect::<Vec<&str>>().join(" ");
+
+		let (name, description) = if name.is_empty() {
+			(argument_or_prompt(None, &tr!("prompt-name")), argument_or_prompt(None, &tr!("prompt-description")))
+		} else {
+			(name, String::new())
+		};
+
+		#[cfg(feature = "url-titles")]
+		let (name, description) = if state.config.fetch_url_titles {
+			crate::url_title::apply(name, description)
+		} else {
+			(name, description)
+		};
+
+		let duplicate = state.entries.iter().position(|entry| entry.is_named(&name));
+
+		if let Some(index) = duplicate {
+			match state.config.duplicates {
+				DuplicatePolicy::Allow => {}
+				DuplicatePolicy::Reject => {
+					eprintln!("{}", tr!("duplicate-rejected", index = index, name = state.entries[index].name.as_str()));
+					return Err(CommandError::Rejected);
+				}
+				DuplicatePolicy::Confirm => {
+					outln!("{}", tr!("duplicate-found", index = index, name = state.entries[index].name.as_str()));
+
+					if !confirm(&tr!("confirm-add-duplicate")) {
+						return Ok(());
+					}
+				}
+				DuplicatePolicy::Merge => {
+					let entry = &mut state.entries[index];
+
+					if !description.is_empty() {
+						let merged = if entry.description.is_empty() {
+							description
+						} else {
+							format!("{} {description}", entry.description)
+						};
+
+						entry.describe(merged);
+						state.modified = true;
+					}
+
+					outln!("{}", tr!("duplicate-merged", index = index, name = state.entries[index].name.as_str()));
+					return Ok(());
+				}
+			}
+		}
+
+		state.add_entry(TodoEntry::new(name, description));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Remove {
+	fn key(&self) -> &'static str {
+		"remove"
+	}
+
+	fn name(&self) -> &'static str {
+		"Remove"
+	}
+
+	fn description(&self) -> String {
+		tr!("remove-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[<index>|all]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let argument = arguments.next();
+
+		if argument == Some("all") {
+			return remove_selection(state);
+		}
+
+		let Some(index) = index_or_pick(state, argument, &tr!("prompt-remove-index"), &tr!("pick-remove"))? else {
+			return Ok(());
+		};
+
+		if let Some(entry) = state.entries.get(index) {
+			let backlinks = state.backlinks(entry.id);
+
+			if !backlinks.is_empty() {
+				eprintln!("{}", tr!("remove-linked", name = entry.name.as_str(), count = backlinks.len()));
+
+				for (source, link) in backlinks {
+					eprintln!("  {source} - {} ({})", state.entries[source].name, link.relation);
+				}
+
+				if !confirm(&tr!("confirm-remove-linked")) {
+					return Ok(());
+				}
+			}
+		}
+
+		let Some(entry) = state.remove_entry(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		outln!("{}", tr!("entry-removed", name = entry.name.as_str()));
+
+		Ok(())
+	}
+}
+
+fn remove_selection(state: &mut State) -> Result<(), CommandError> {
+	let selected = state.selection.iter()
+		.filter_map(|id| state.position_of(*id))
+		.collect::<Vec<usize>>();
+
+	if selected.is_empty() {
+		eprintln!("{}", tr!("no-results"));
+		return Err(CommandError::NotFound);
+	}
+
+	for index in &selected {
+		outln!("{index} - {}", state.entries[*index].name);
+	}
+
+	if !confirm(&tr!("confirm-remove-results", count = selected.len())) {
+		return Ok(());
+	}
+
+	for id in std::mem::take(&mut state.selection) {
+		if let Some(entry) = state.position_of(id).and_then(|index| state.remove_entry(index)) {
+			outln!("{}", tr!("entry-removed", name = entry.name.as_str()));
+		}
+	}
+
+	Ok(())
+}
+
+impl CommandHandler for Move {
+	fn key(&self) -> &'static str {
+		"move"
+	}
+
+	fn name(&self) -> &'static str {
+		"Move"
+	}
+
+	fn description(&self) -> String {
+		tr!("move-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> [to] <position>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-move-index"))
+		)?;
+		let position = parse_index(
+			&argument_or_prompt(
+				arguments.find(|argument| *argument != "to"),
+				&tr!("prompt-move-position")
+			)
+		)?;
+
+		if index >= state.entries.len() {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		}
+
+		if position >= state.entries.len() {
+			eprintln!("{}", tr!("position-out-of-range", position = position));
+			return Err(CommandError::InvalidInput);
+		}
+
+		let entry = state.entries.remove(index);
+		outln!("{}", tr!("entry-moved", name = entry.name.as_str(), position = position));
+		state.entries.insert(position, entry);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Swap {
+	fn key(&self) -> &'static str {
+		"swap"
+	}
+
+	fn name(&self) -> &'static str {
+		"Swap"
+	}
+
+	fn description(&self) -> String {
+		tr!("swap-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> <index>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let first = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-swap-first"))
+		)?;
+		let second = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-swap-second"))
+		)?;
+
+		if first >= state.entries.len() {
+			eprintln!("{}", tr!("entry-not-found", index = first));
+			return Err(CommandError::NotFound);
+		}
+
+		if second >= state.entries.len() {
+			eprintln!("{}", tr!("entry-not-found", index = second));
+			return Err(CommandError::NotFound);
+		}
+
+		state.entries.swap(first, second);
+		outln!(
+			"{}",
+			tr!(
+				"entries-swapped",
+				first = state.entries[second].name.as_str(),
+				second = state.entries[first].name.as_str()
+			)
+		);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Show {
+	fn key(&self) -> &'static str {
+		"show"
+	}
+
+	fn name(&self) -> &'static str {
+		"Show"
+	}
+
+	fn description(&self) -> String {
+		tr!("show-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> [--absolute] [--history]"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		let arguments = arguments.collect::<Vec<&str>>();
+		let absolute = arguments.contains(&"--absolute");
+		let history = arguments.contains(&"--history");
+		let index = state.resolve_index(
+			&argument_or_prompt(
+				arguments.iter().find(|argument| !argument.starts_with("--")).copied(),
+				&tr!("prompt-show-index")
+			)
+		)?;
+
+		let Some(entry) = state.entries.get(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		outln!("{index} - {}", entry.name);
+		outln!("{}", tr!("show-entry-description", description = entry.description.as_str()));
+
+		if let Some(source) = &entry.source {
+			outln!("{}", tr!("show-entry-source", path = source.path.as_str(), line = source.line));
+		}
+
+		if let Some(assignee) = &entry.assignee {
+			outln!("{}", tr!("show-entry-assignee", name = assignee.as_str()));
+		}
+
+		if let Some(location) = &entry.location {
+			outln!("{}", tr!("show-entry-location", location = location.as_str()));
+		}
+
+		if let Some(color) = entry.color {
+			outln!("{}", tr!("show-entry-color", color = color.paint(color.name())));
+		}
+
+		if let Some(estimate) = entry.estimate() {
+			outln!("{}", tr!("show-entry-estimate", duration = format_duration(estimate)));
+		}
+
+		let now = crate::now();
+		out!("{}", tr!("show-entry-tracked", duration = format_duration(entry.tracked_time(now))));
+
+		if let Some(start) = entry.tracking_since {
+			if absolute {
+				out!(
+					" {}",
+					tr!(
+						"show-entry-tracking-since",
+						start = locale::format_timestamp(start, &state.config)
+					)
+				);
+			} else {
+				out!(" {}", tr!("show-entry-tracking-started", ago = locale::format_relative(start, now)));
+			}
+		}
+
+		outln!();
+
+		if !entry.links.is_empty() {
+			outln!("{}", tr!("show-entry-links"));
+
+			for link in &entry.links {
+				if let Some(target) = state.position_of(link.target) {
+					outln!("  {} {target} - {}", link.relation, state.entries[target].name);
+				}
+			}
+		}
+
+		let backlinks = state.backlinks(entry.id);
+
+		if !backlinks.is_empty() {
+			outln!("{}", tr!("show-entry-backlinks"));
+
+			for (source, link) in backlinks {
+				outln!("  {source} - {} ({})", state.entries[source].name, link.relation);
+			}
+		}
+
+		if !history {
+			return Ok(());
+		}
+
+		if entry.history.is_empty() {
+			outln!("{}", tr!("show-entry-no-history"));
+			return Ok(());
+		}
+
+		outln!("{}", tr!("show-entry-history"));
+
+		for change in &entry.history {
+			outln!(
+				"  {}",
+				tr!(
+					"show-entry-change",
+					timestamp = if absolute {
+						locale::format_timestamp(change.timestamp, &state.config)
+					} else {
+						locale::format_relative(change.timestamp, now)
+					},
+					field = change.field.as_str(),
+					old = change.old.as_str(),
+					new = change.new.as_str()
+				)
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Edit {
+	fn key(&self) -> &'static str {
+		"edit"
+	}
+
+	fn name(&self) -> &'static str {
+		"Edit"
+	}
+
+	fn description(&self) -> String {
+		tr!("edit-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"where <filter|all> set <field>=<value>"
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		let arguments = arguments.collect::<Vec<&str>>();
+
+		let (Some(&"where"), Some(set)) = (
+			arguments.first(),
+			arguments.iter().position(|argument| *argument == "set")
+		) else {
+			eprintln!("{}", tr!("edit-usage"));
+			return Err(CommandError::InvalidInput);
+		};
+
+		let expression = arguments[1..set].join(" ");
+
+		if expression.is_empty() {
+			eprintln!("{}", tr!("edit-filter-empty"));
+			return Err(CommandError::InvalidInput);
+		}
+
+		let filter = match Filter::parse(if expression == "all" { "" } else { expression.as_str() }) {
+			Ok(filter) => filter,
+			Err(error) => {
+				eprintln!("{error}");
+				return Err(CommandError::InvalidInput);
+			}
+		};
+		let assignment = arguments[set + 1..].join(" ");
+
+		let Some((field, value)) = assignment.split_once('=') else {
+			eprintln!("{}", tr!("edit-usage"));
+			return Err(CommandError::InvalidInput);
+		};
+
+		let apply: Box<dyn Fn(&mut TodoEntry)> = match field {
+			"name" if value.trim().is_empty() => {
+				eprintln!("{}", tr!("name-empty"));
+				return Err(CommandError::InvalidInput);
+			}
+			"name" => Box::new(|entry| entry.rename(value.to_string())),
+			"description" => Box::new(|entry| entry.describe(value.to_string())),
+			"assignee" => Box::new(|entry| entry.assign(Some(value.to_string()).filter(|value| !value.is_empty()))),
+			"location" => Box::new(|entry| entry.locate(Some(value.to_string()).filter(|value| !value.is_empty()))),
+			"color" => {
+				let color = match value {
+					"" | "none" => None,
+					name => {
+						let Some(color) = Color::parse(name) else {
+							eprintln!(
+								"{}",
+								tr!("unknown-color", color = name, colors = Color::ALL.map(Color::name).join(", "))
+							);
+							return Err(CommandError::InvalidInput);
+						};
+
+						Some(color)
+					}
+				};
+
+				Box::new(move |entry| entry.paint(color))
+			}
+			"estimate" => {
+				let Some(estimate) = parse_duration(value) else {
+					eprintln!("{}", tr!("invalid-duration"));
+					return Err(CommandError::InvalidInput);
+				};
+
+				Box::new(move |entry| entry.set_estimate(estimate))
+			}
+			_ => {
+				eprintln!("{}", tr!("edit-unknown-field", field = field));
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let mut changes = Vec::<(usize, TodoEntry)>::new();
+
+		for (index, entry) in state.entries.iter().enumerate().filter(|(_, entry)| filter.matches(entry)) {
+			let mut edited = entry.clone();
+			apply(&mut edited);
+
+			let Some(change) = edited.history.get(entry.history.len()) else {
+				continue;
+			};
+
+			outln!("{}", tr!("entry-edited", index = index, old = change.old.as_str(), new = change.new.as_str()));
+			changes.push((index, edited));
+		}
+
+		if !changes.is_empty() && !confirm(&tr!("confirm-replace", count = changes.len())) {
+			return Ok(());
+		}
+
+		let count = changes.len();
+
+		for (index, edited) in changes {
+			state.entries[index] = edited;
+		}
+
+		outln!("{}", tr!("entries-edited", count = count));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Rename {
+	fn key(&self) -> &'static str {
+		"rename"
+	}
+
+	fn name(&self) -> &'static str {
+		"Rename"
+	}
+
+	fn description(&self) -> String {
+		tr!("rename-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> <name>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-rename-index"))
+		)?;
+
+		let name = arguments.collect::<Vec<&str>>().join(" ");
+		let name = argument_or_prompt(Some(name.as_str()).filter(|name| !name.is_empty()), &tr!("prompt-name"));
+
+		if name.trim().is_empty() {
+			eprintln!("{}", tr!("name-empty"));
+			return Err(CommandError::InvalidInput);
+		}
+
+		let Some(entry) = state.entries.get_mut(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		outln!("{}", tr!("entry-edited", index = index, old = entry.name.as_str(), new = name.as_str()));
+		entry.rename(name);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Describe {
+	fn key(&self) -> &'static str {
+		"describe"
+	}
+
+	fn name(&self) -> &'static str {
+		"Describe"
+	}
+
+	fn description(&self) -> String {
+		tr!("describe-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> <text>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-describe-index"))
+		)?;
+
+		let description = arguments.collect::<Vec<&str>>().join(" ");
+		let description = argument_or_prompt(
+			Some(description.as_str()).filter(|description| !description.is_empty()),
+			&tr!("prompt-description")
+		);
+
+		let Some(entry) = state.entries.get_mut(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		outln!(
+			"{}",
+			tr!("entry-edited", index = index, old = entry.description.as_str(), new = description.as_str())
+		);
+		entry.describe(description);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Replace {
+	fn key(&self) -> &'static str {
+		"replace"
+	}
+
+	fn name(&self) -> &'static str {
+		"Replace"
+	}
+
+	fn description(&self) -> String {
+		tr!("replace-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<pattern> <replacement> [--names|--descriptions]"
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		let (flags, arguments) = arguments.partition::<Vec<&str>, _>(|argument| argument.starts_with("--"));
+		let names = flags.is_empty() || flags.contains(&"--names");
+		let descriptions = flags.is_empty() || flags.contains(&"--descriptions");
+
+		let pattern = argument_or_prompt(arguments.first().copied(), &tr!("prompt-replace-pattern"));
+		let replacement = argument_or_prompt(arguments.get(1).copied(), &tr!("prompt-replace-replacement"));
+
+		let pattern = match Regex::new(&pattern) {
+			Ok(pattern) => pattern,
+			Err(error) => {
+				eprintln!("{}", tr!("invalid-pattern", error = error.to_string()));
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let mut changes = Vec::<(usize, Option<String>, Option<String>)>::new();
+
+		for (index, entry) in state.entries.iter().enumerate() {
+			let name = Some(pattern.replace_all(&entry.name, replacement.as_str()).into_owned())
+				.filter(|name| names && *name != entry.name);
+			let description = Some(pattern.replace_all(&entry.description, replacement.as_str()).into_owned())
+				.filter(|description| descriptions && *description != entry.description);
+
+			if name.is_none() && description.is_none() {
+				continue;
+			}
+
+			outln!("{index} - {}", entry.name);
+
+			if let Some(name) = &name {
+				outln!("- {}", entry.name);
+				outln!("+ {name}");
+			}
+
+			if let Some(description) = &description {
+				outln!("- {}", entry.description);
+				outln!("+ {description}");
+			}
+
+			changes.push((index, name, description));
+		}
+
+		if changes.is_empty() {
+			outln!("{}", tr!("nothing-to-replace"));
+			return Ok(());
+		}
+
+		if !confirm(&tr!("confirm-replace", count = changes.len())) {
+			return Ok(());
+		}
+
+		for (index, name, description) in &changes {
+			let entry = &mut state.entries[*index];
+
+			if let Some(name) = name {
+				entry.rename(name.clone());
+			}
+
+			if let Some(description) = description {
+				entry.describe(description.clone());
+			}
+		}
+
+		outln!("{}", tr!("entries-edited", count = changes.len()));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Link {
+	fn key(&self) -> &'static str {
+		"link"
+	}
+
+	fn name(&self) -> &'static str {
+		"Link"
+	}
+
+	fn description(&self) -> String {
+		tr!("link-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> <index> [relation]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let source = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-link-source"))
+		)?;
+		let target = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-link-target"))
+		)?;
+		let relation = arguments.next().unwrap_or(DEFAULT_LINK_RELATION).to_string();
+
+		if source >= state.entries.len() {
+			eprintln!("{}", tr!("entry-not-found", index = source));
+			return Err(CommandError::NotFound);
+		}
+
+		if target >= state.entries.len() {
+			eprintln!("{}", tr!("entry-not-found", index = target));
+			return Err(CommandError::NotFound);
+		}
+
+		if source == target {
+			eprintln!("{}", tr!("link-to-self"));
+			return Err(CommandError::InvalidInput);
+		}
+
+		let link = EntryLink {
+			target: state.entries[target].id,
+			relation,
+		};
+
+		if state.entries[source].links.contains(&link) {
+			outln!("{}", tr!("already-linked"));
+			return Ok(());
+		}
+
+		outln!(
+			"{}",
+			tr!(
+				"entries-linked",
+				source = state.entries[source].name.as_str(),
+				relation = link.relation.as_str(),
+				target = state.entries[target].name.as_str()
+			)
+		);
+
+		state.entries[source].links.push(link);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Estimate {
+	fn key(&self) -> &'static str {
+		"estimate"
+	}
+
+	fn name(&self) -> &'static str {
+		"Estimate"
+	}
+
+	fn description(&self) -> String {
+		tr!("estimate-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> <duration>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-estimate-index"))
+		)?;
+
+		let Some(estimate) = parse_duration(&argument_or_prompt(arguments.next(), &tr!("prompt-estimate-duration"))) else {
+			eprintln!("{}", tr!("invalid-duration"));
+			return Err(CommandError::InvalidInput);
+		};
+
+		let Some(entry) = state.entries.get_mut(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		entry.set_estimate(estimate);
+
+		if estimate.is_zero() {
+			outln!("{}", tr!("estimate-cleared", name = entry.name.as_str()));
+		} else {
+			outln!("{}", tr!("estimate-set", name = entry.name.as_str(), duration = format_duration(estimate)));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Assign {
+	fn key(&self) -> &'static str {
+		"assign"
+	}
+
+	fn name(&self) -> &'static str {
+		"Assign"
+	}
+
+	fn description(&self) -> String {
+		tr!("assign-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> [name]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-assign-index"))
+		)?;
+
+		let assignee = arguments.collect::<Vec<&str>>().join(" ");
+		let assignee = argument_or_prompt(
+			Some(assignee.as_str()).filter(|assignee| !assignee.is_empty()),
+			&tr!("prompt-assignee")
+		);
+
+		let Some(entry) = state.entries.get_mut(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		if assignee.is_empty() {
+			entry.assign(None);
+			outln!("{}", tr!("entry-unassigned", name = entry.name.as_str()));
+		} else {
+			outln!("{}", tr!("entry-assigned", name = entry.name.as_str(), assignee = assignee.as_str()));
+			entry.assign(Some(assignee));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Locate {
+	fn key(&self) -> &'static str {
+		"locate"
+	}
+
+	fn name(&self) -> &'static str {
+		"Locate"
+	}
+
+	fn description(&self) -> String {
+		tr!("locate-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> [place]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-locate-index"))
+		)?;
+
+		let location = arguments.collect::<Vec<&str>>().join(" ");
+		let location = argument_or_prompt(
+			Some(location.as_str()).filter(|location| !location.is_empty()),
+			&tr!("prompt-location")
+		);
+
+		let Some(entry) = state.entries.get_mut(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		if location.is_empty() {
+			entry.locate(None);
+			outln!("{}", tr!("entry-location-cleared", name = entry.name.as_str()));
+		} else {
+			outln!("{}", tr!("entry-located", name = entry.name.as_str(), location = location.as_str()));
+			entry.locate(Some(location));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Label {
+	fn key(&self) -> &'static str {
+		"color"
+	}
+
+	fn name(&self) -> &'static str {
+		"Color"
+	}
+
+	fn description(&self) -> String {
+		tr!("color-description", colors = Color::ALL.map(Color::name).join(", "))
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> <color>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-color-index"))
+		)?;
+
+		let name = argument_or_prompt(arguments.next(), &tr!("prompt-color"));
+		let color = match name.as_str() {
+			"" | "none" => None,
+			name => {
+				let Some(color) = Color::parse(name) else {
+					eprintln!(
+						"{}",
+						tr!("unknown-color", color = name, colors = Color::ALL.map(Color::name).join(", "))
+					);
+					return Err(CommandError::InvalidInput);
+				};
+
+				Some(color)
+			}
+		};
+
+		let Some(entry) = state.entries.get_mut(index) else {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		};
+
+		entry.paint(color);
+
+		match color {
+			Some(color) => outln!("{}", tr!("entry-colored", name = color.paint(&entry.name), color = color.name())),
+			None => outln!("{}", tr!("entry-color-cleared", name = entry.name.as_str())),
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Focus {
+	fn key(&self) -> &'static str {
+		"focus"
+	}
+
+	fn name(&self) -> &'static str {
+		"Focus"
+	}
+
+	fn description(&self) -> String {
+		tr!("focus-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[count]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let count = arguments.next().map_or(DEFAULT_FOCUS_COUNT, |count| {
+			count.parse::<usize>().unwrap_or_else(|_| {
+				eprintln!("{}", tr!("focus-invalid-count", count = DEFAULT_FOCUS_COUNT));
+				DEFAULT_FOCUS_COUNT
+			})
+		});
+
+		if count == 0 {
+			eprintln!("{}", tr!("focus-needs-entry"));
+			return Err(CommandError::InvalidInput);
+		}
+
+		state.focus = Some(count);
+		state.planned.clear();
+		outln!("{}", tr!("focus-enabled", count = count));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Unfocus {
+	fn key(&self) -> &'static str {
+		"unfocus"
+	}
+
+	fn name(&self) -> &'static str {
+		"Unfocus"
+	}
+
+	fn description(&self) -> String {
+		tr!("unfocus-description")
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		let planned = !std::mem::take(&mut state.planned).is_empty();
+
+		if state.focus.take().is_some() || planned {
+			outln!("{}", tr!("focus-disabled"));
+		} else {
+			outln!("{}", tr!("focus-not-enabled"));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Plan {
+	fn key(&self) -> &'static str {
+		"plan"
+	}
+
+	fn name(&self) -> &'static str {
+		"Plan"
+	}
+
+	fn description(&self) -> String {
+		tr!("plan-description")
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		if state.entries.is_empty() {
+			outln!("{}", tr!("nothing-to-plan"));
+			return Ok(());
+		}
+
+		let mut candidates = state.entries.iter().map(|entry| entry.id).collect::<VecDeque<usize>>();
+		let mut accepted = Vec::new();
+		let mut planned = chrono::Duration::zero();
+
+		'candidates: while let Some(id) = candidates.pop_front() {
+			let Some(entry) = state.position_of(id).map(|index| &state.entries[index]) else {
+				continue;
+			};
+
+			match entry.estimate() {
+				Some(estimate) => outln!("{} ({})", entry.name, format_duration(estimate)),
+				None => outln!("{}", entry.name),
+			}
+
+			loop {
+				match argument_or_prompt(None, &tr!("prompt-plan")).to_lowercase().as_str() {
+					"a" | "accept" => {
+						planned += entry.estimate().unwrap_or_else(chrono::Duration::zero);
+						accepted.push(id);
+						break;
+					}
+					"" | "s" | "skip" => break,
+					"z" | "snooze" => {
+						candidates.push_back(id);
+						break;
+					}
+					"q" | "quit" => break 'candidates,
+					_ => eprintln!("{}", tr!("unknown-input")),
+				}
+			}
+		}
+
+		if accepted.is_empty() {
+			outln!("{}", tr!("plan-empty"));
+			return Ok(());
+		}
+
+		outln!("{}", tr!("plan-done", count = accepted.len()));
+		state.planned = accepted;
+		state.focus = None;
+
+		if !planned.is_zero() {
+			outln!("{}", tr!("list-estimated", duration = format_duration(planned)));
+		}
+
+		if let Some(capacity) = state.config.daily_capacity().filter(|capacity| planned > *capacity) {
+			eprintln!("{}", tr!("over-capacity", capacity = format_duration(capacity)));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Pick {
+	fn key(&self) -> &'static str {
+		"pick"
+	}
+
+	fn name(&self) -> &'static str {
+		"Pick"
+	}
+
+	fn description(&self) -> String {
+		tr!("pick-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		if state.entries.is_empty() {
+			outln!("{}", tr!("nothing-to-pick"));
+			return Ok(());
+		}
+
+		let index = crate::rng().random_range(0..state.entries.len());
+		let entry = &state.entries[index];
+
+		outln!(
+			"{}",
+			tr!("picked", index = index, name = entry.name.as_str(), description = entry.description.as_str())
+		);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Count {
+	fn key(&self) -> &'static str {
+		"count"
+	}
+
+	fn name(&self) -> &'static str {
+		"Count"
+	}
+
+	fn description(&self) -> String {
+		tr!("count-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[filter]"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		let filter = match Filter::parse(&arguments.collect::<Vec<&str>>().join(" ")) {
+			Ok(filter) => filter,
+			Err(error) => {
+				eprintln!("{error}");
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		outln!(
+			"{}",
+			state.entries.iter().filter(|entry| filter.matches(entry)).count()
+		);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Summary {
+	fn key(&self) -> &'static str {
+		"summary"
+	}
+
+	fn name(&self) -> &'static str {
+		"Summary"
+	}
+
+	fn description(&self) -> String {
+		tr!("summary-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		let mut summary = tr!("summary-open", count = state.entries.len());
+
+		if let Some(entry) = state.entries.iter().find(|entry| entry.tracking_since.is_some()) {
+			summary.push_str(&tr!("summary-tracking", name = entry.name.as_str()));
+		}
+
+		outln!("{summary}");
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Status {
+	fn key(&self) -> &'static str {
+		"status"
+	}
+
+	fn name(&self) -> &'static str {
+		"Status"
+	}
+
+	fn description(&self) -> String {
+		tr!("status-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[--format waybar|i3blocks|tmux]"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let format = match (arguments.next(), arguments.next()) {
+			(None, _) => "plain",
+			(Some("--format"), Some(format)) => format,
+			_ => {
+				eprintln!("{}", tr!("status-usage"));
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let tracking = state.entries.iter().find(|entry| entry.tracking_since.is_some());
+		let mut text = tr!("summary-open", count = state.entries.len());
+
+		if let Some(entry) = tracking {
+			text.push_str(&tr!("summary-tracking", name = entry.name.as_str()));
+		} else if let Some(entry) = state.entries.first() {
+			text.push_str(&tr!("summary-next", name = entry.name.as_str()));
+		}
+
+		let class = match (tracking, state.entries.is_empty()) {
+			(Some(_), _) => "tracking",
+			(None, true) => "empty",
+			(None, false) => "idle",
+		};
+
+		match format.to_lowercase().as_str() {
+			"plain" => outln!("{text}"),
+			"waybar" => {
+				let tooltip = state.focused()
+					.unwrap_or_else(|| state.entries.iter().enumerate().take(DEFAULT_FOCUS_COUNT).collect())
+					.into_iter()
+					.map(|(index, entry)| format!("{index} - {}", entry.name))
+					.collect::<Vec<String>>()
+					.join("\n");
+
+				outln!(
+					"{}",
+					serde_json::json!({
+						"text": text,
+						"alt": class,
+						"class": class,
+						"tooltip": tooltip,
+					})
+				);
+			}
+			"i3blocks" => {
+				outln!("{text}");
+				outln!("{}", state.entries.len());
+			}
+			"tmux" => {
+				let color = if tracking.is_some() { "green" } else { "default" };
+				outln!("#[fg={color}]{}#[default]", text.replace('#', "##"));
+			}
+			_ => {
+				eprintln!("{}", tr!("status-unknown-format", format = format));
+				return Err(CommandError::InvalidInput);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Clear {
+	fn key(&self) -> &'static str {
+		"clear"
+	}
+
+	fn name(&self) -> &'static str {
+		"Clear"
+	}
+
+	fn description(&self) -> String {
+		tr!("clear-description")
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		if state.entries.is_empty() {
+			outln!("{}", tr!("nothing-to-clear"));
+			return Ok(());
+		}
+
+		let entries_count = state.entries.len();
+
+		if !confirm(&tr!("confirm-clear", count = entries_count)) {
+			return Ok(());
+		}
+
+		let backup = if state.dry_run {
+			None
+		} else {
+			let Some(path) = write_backup_file(state) else {
+				eprintln!("{}", tr!("backup-failed"));
+				return Err(CommandError::Io);
+			};
+
+			Some(path)
+		};
+
+		state.entries.clear();
+		state.planned.clear();
+		state.selection.clear();
+		outln!("{}", tr!("entries-cleared", count = entries_count));
+
+		if let Some(path) = backup {
+			outln!("{}", tr!("clear-restore-hint", path = path));
+		}
+
+		Ok(())
+	}
+}
+
+fn option_value(arguments: &[&str], flag: &str) -> Option<String> {
+	let position = arguments.iter().position(|argument| *argument == flag)?;
+	let value = arguments[position + 1..].iter()
+		.take_while(|argument| !argument.starts_with("--"))
+		.copied()
+		.collect::<Vec<&str>>()
+		.join(" ");
+
+	let unquoted = ['"', '\''].into_iter()
+		.find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+		.map(ToString::to_string);
+
+	Some(unquoted.unwrap_or(value))
+}
@@ -0,0 +1,398 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::interop::{EXPORT_FORMATS, IMPORT_FORMATS};
+use super::{Arguments, CommandError, CommandHandler, CommandRegistry};
+use crate::locale::{self, tr};
+use crate::output::{out, outln};
+use crate::{
+	confirm, docs, is_interactive, read_state_file, run_line, write_state_file, State, STATE_FILE_PATH,
+	STATE_MANIFEST_VERSION,
+};
+
+pub struct Help;
+pub struct Capabilities;
+pub struct Version;
+pub struct History;
+pub struct Tutorial;
+pub struct Docs;
+pub struct Exit;
+
+#[derive(Serialize)]
+struct CapabilityReport {
+	pub version: &'static str,
+	pub features: Vec<&'static str>,
+	pub formats: Vec<&'static str>,
+	pub backends: Vec<&'static str>,
+	pub manifest_versions: Vec<usize>,
+	pub locales: Vec<&'static str>,
+	pub commands: Vec<&'static str>,
+}
+
+impl CommandHandler for Help {
+	fn key(&self) -> &'static str {
+		"help"
+	}
+
+	fn name(&self) -> &'static str {
+		"Help"
+	}
+
+	fn description(&self) -> String {
+		tr!("help-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, _state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		for command in CommandRegistry::builtin().iter() {
+			outln!("{} ({}) : {}", command.name(), format!("{} {}", command.key(), command.usage()).trim_end(), command.description());
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Capabilities {
+	fn key(&self) -> &'static str {
+		"capabilities"
+	}
+
+	fn name(&self) -> &'static str {
+		"Capabilities"
+	}
+
+	fn description(&self) -> String {
+		tr!("capabilities-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[--json]"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, _state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let capabilities = CapabilityReport::new();
+
+		if arguments.any(|argument| argument == "--json") {
+			outln!(
+				"{}",
+				serde_json::to_string_pretty(&capabilities).unwrap_or_else(|_| {
+					eprintln!("{}", tr!("capabilities-serialize-failed"));
+					String::new()
+				})
+			);
+		} else {
+			outln!("{}", tr!("capabilities-version", version = capabilities.version));
+			outln!(
+				"{}",
+				tr!(
+					"capabilities-features",
+					features = if capabilities.features.is_empty() {
+						tr!("capabilities-none")
+					} else {
+						capabilities.features.join(", ")
+					}
+				)
+			);
+			outln!("{}", tr!("capabilities-formats", formats = capabilities.formats.join(", ")));
+			outln!("{}", tr!("capabilities-backends", backends = capabilities.backends.join(", ")));
+			outln!(
+				"{}",
+				tr!(
+					"capabilities-manifest-versions",
+					versions = capabilities.manifest_versions.iter()
+						.map(ToString::to_string)
+						.collect::<Vec<String>>()
+						.join(", ")
+				)
+			);
+			outln!("{}", tr!("capabilities-locales", locales = capabilities.locales.join(", ")));
+			outln!("{}", tr!("capabilities-commands", commands = capabilities.commands.join(", ")));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Version {
+	fn key(&self) -> &'static str {
+		"version"
+	}
+
+	fn name(&self) -> &'static str {
+		"Version"
+	}
+
+	fn description(&self) -> String {
+		tr!("version-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		let capabilities = CapabilityReport::new();
+		let commit = env!("TODOTRACKER_GIT_COMMIT");
+		let built = env!("TODOTRACKER_BUILD_TIMESTAMP").parse::<i64>().ok()
+			.and_then(|timestamp| DateTime::<Utc>::from_timestamp(timestamp, 0));
+
+		outln!("todotracker {}", capabilities.version);
+		outln!(
+			"{}",
+			tr!("version-commit", commit = if commit.is_empty() { tr!("version-unknown") } else { commit.to_string() })
+		);
+		outln!(
+			"{}",
+			tr!(
+				"version-built",
+				date = built.map_or_else(|| tr!("version-unknown"), |built| locale::format_timestamp(built, &state.config))
+			)
+		);
+		outln!(
+			"{}",
+			tr!(
+				"capabilities-features",
+				features = if capabilities.features.is_empty() {
+					tr!("capabilities-none")
+				} else {
+					capabilities.features.join(", ")
+				}
+			)
+		);
+		outln!("{}", tr!("capabilities-backends", backends = capabilities.backends.join(", ")));
+
+		if PathBuf::from(STATE_FILE_PATH).exists() {
+			match read_state_file() {
+				Some(saved) => outln!(
+					"{}",
+					tr!(
+						"version-manifest",
+						version = STATE_MANIFEST_VERSION,
+						path = STATE_FILE_PATH,
+						saved = saved.manifest_version
+					)
+				),
+				None => outln!(
+					"{}",
+					tr!("version-manifest-unreadable", version = STATE_MANIFEST_VERSION, path = STATE_FILE_PATH)
+				),
+			}
+		} else {
+			outln!("{}", tr!("version-manifest-missing", version = STATE_MANIFEST_VERSION, path = STATE_FILE_PATH));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for History {
+	fn key(&self) -> &'static str {
+		"history"
+	}
+
+	fn name(&self) -> &'static str {
+		"History"
+	}
+
+	fn description(&self) -> String {
+		tr!("history-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		for (number, line) in state.command_history.iter().enumerate() {
+			outln!("{:>4}  {line}", number + 1);
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Tutorial {
+	fn key(&self) -> &'static str {
+		"tutorial"
+	}
+
+	fn name(&self) -> &'static str {
+		"Tutorial"
+	}
+
+	fn description(&self) -> String {
+		tr!("tutorial-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		if !is_interactive() {
+			eprintln!("{}", tr!("input-required", command = self.key()));
+			return Err(CommandError::Rejected);
+		}
+
+		type Check = fn(&State, &State, &str) -> bool;
+
+		let steps: [(String, Check); 4] = [
+			(tr!("tutorial-add"), |before, after, _| after.entries.len() > before.entries.len()),
+			(tr!("tutorial-list"), |_, _, key| key == "list"),
+			(tr!("tutorial-remove"), |before, after, _| after.entries.len() < before.entries.len()),
+			(tr!("tutorial-save"), |_, _, key| key == "save"),
+		];
+
+		let mut sandbox = State::new();
+		sandbox.config = state.config.clone();
+		sandbox.dry_run = true;
+
+		outln!("{}", tr!("tutorial-welcome"));
+
+		for (instruction, check) in steps {
+			loop {
+				outln!("{instruction}");
+
+				let mut line = String::new();
+
+				let read = std::io::stdin().read_line(&mut line).unwrap_or_default();
+				let key = line.split_whitespace().next().unwrap_or_default().to_lowercase();
+
+				if read == 0 || key == "exit" {
+					outln!("{}", tr!("tutorial-stopped"));
+					return Ok(());
+				}
+
+				if key == self.key() {
+					continue;
+				}
+
+				let before = sandbox.clone();
+				let result = run_line(&mut sandbox, line.trim());
+
+				if result.is_ok() && check(&before, &sandbox, &key) {
+					outln!("{}", tr!("tutorial-step-done"));
+					break;
+				}
+
+				outln!("{}", tr!("tutorial-try-again"));
+			}
+		}
+
+		outln!("{}", tr!("tutorial-finished"));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Docs {
+	fn key(&self) -> &'static str {
+		"docs"
+	}
+
+	fn name(&self) -> &'static str {
+		"Docs"
+	}
+
+	fn description(&self) -> String {
+		tr!("docs-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"man|help"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, _state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		match arguments.next() {
+			Some("man") => out!("{}", docs::man()),
+			Some("help") => out!("{}", docs::help()),
+			_ => {
+				eprintln!("{}", tr!("docs-usage-command"));
+				return Err(CommandError::InvalidInput);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Exit {
+	fn key(&self) -> &'static str {
+		"exit"
+	}
+
+	fn name(&self) -> &'static str {
+		"Exit"
+	}
+
+	fn description(&self) -> String {
+		tr!("exit-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		if state.modified && !state.dry_run && !state.read_only {
+			if state.config.save_on_exit {
+				if state.stale && !confirm(&tr!("confirm-overwrite-changed", path = STATE_FILE_PATH)) {
+					return Ok(());
+				}
+
+				if !write_state_file(state) {
+					return Err(CommandError::Io);
+				}
+
+				state.modified = false;
+				state.stale = false;
+				outln!("{}", tr!("state-saved", path = STATE_FILE_PATH));
+			} else if PathBuf::from(STATE_FILE_PATH).exists() && !confirm(&tr!("confirm-exit")) {
+				return Ok(());
+			}
+		}
+
+		state.exit = true;
+
+		Ok(())
+	}
+}
+
+impl CapabilityReport {
+	fn new() -> Self {
+		let mut features = Vec::new();
+
+		if cfg!(feature = "url-titles") {
+			features.push("url-titles");
+		}
+
+		if cfg!(all(target_os = "linux", feature = "dbus")) {
+			features.push("dbus");
+		}
+
+		CapabilityReport {
+			version: env!("CARGO_PKG_VERSION"),
+			features,
+			formats: IMPORT_FORMATS.into_iter()
+				.chain(EXPORT_FORMATS.into_iter().filter(|format| !IMPORT_FORMATS.contains(format)))
+				.collect(),
+			backends: vec!["file"],
+			manifest_versions: (1..=STATE_MANIFEST_VERSION).collect(),
+			locales: locale::supported(),
+			commands: CommandRegistry::builtin().iter().map(CommandHandler::key).collect(),
+		}
+	}
+}
@@ -0,0 +1,703 @@
+use std::path::{Path, PathBuf};
+
+use super::{Arguments, CommandError, CommandHandler};
+use crate::filter::Filter;
+use crate::locale::tr;
+use crate::output::{out, outln};
+#[cfg(unix)]
+use crate::{control, parse_index};
+use crate::{
+	argument_or_prompt, bundle, confirm, export, history, import, is_interactive, mcp, script, server, Config,
+	State, CONFIG_FILE_PATH, DEFAULT_SERVER_HOST, DEFAULT_SERVER_PORT, STATE_FILE_PATH, STATE_MANIFEST_VERSION,
+};
+
+pub struct Serve;
+pub struct Ctl;
+pub struct Mcp;
+pub struct Import;
+pub struct Export;
+pub struct Vault;
+pub struct Script;
+
+pub const IMPORT_FORMATS: [&str; 10] = [
+	"ron", "json", "taskwarrior", "org", "trello", "google-tasks", "maildir", "csv", "todo-txt", "markdown",
+];
+pub const EXPORT_FORMATS: [&str; 4] = ["org", "html", "csv", "json"];
+
+impl CommandHandler for Serve {
+	fn key(&self) -> &'static str {
+		"serve"
+	}
+
+	fn name(&self) -> &'static str {
+		"Serve"
+	}
+
+	fn description(&self) -> String {
+		tr!("serve-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[--port <port>] [--host <host>]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let mut host = DEFAULT_SERVER_HOST.to_string();
+		let mut port = DEFAULT_SERVER_PORT;
+
+		while let Some(argument) = arguments.next() {
+			match argument {
+				"--port" => {
+					let Some(value) = arguments.next().and_then(|value| value.parse::<u16>().ok()) else {
+						eprintln!("{}", tr!("invalid-port"));
+						return Err(CommandError::InvalidInput);
+					};
+
+					port = value;
+				}
+				"--host" => {
+					let Some(value) = arguments.next() else {
+						eprintln!("{}", tr!("missing-host"));
+						return Err(CommandError::InvalidInput);
+					};
+
+					value.clone_into(&mut host);
+				}
+				_ => {}
+			}
+		}
+
+		server::serve(state, &host, port);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Ctl {
+	fn key(&self) -> &'static str {
+		"ctl"
+	}
+
+	fn name(&self) -> &'static str {
+		"Ctl"
+	}
+
+	fn description(&self) -> String {
+		tr!("ctl-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"list|add <name>|remove <index>"
+	}
+
+	#[cfg(unix)]
+	fn execute(&self, _state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		if control::is_listening() {
+			eprintln!("{}", tr!("ctl-own-session"));
+			return Err(CommandError::Rejected);
+		}
+
+		let request = match arguments.next().unwrap_or_default().to_lowercase().as_str() {
+			"list" => control::ControlRequest::List,
+			"add" => {
+				let name = arguments.collect::<Vec<&str>>().join(" ");
+
+				if name.is_empty() {
+					eprintln!("{}", tr!("ctl-add-usage"));
+					return Err(CommandError::InvalidInput);
+				}
+
+				control::ControlRequest::Add {
+					name,
+					description: String::new(),
+				}
+			}
+			"remove" => {
+				let index = parse_index(
+					&argument_or_prompt(arguments.next(), &tr!("prompt-remove-index"))
+				)?;
+
+				control::ControlRequest::Remove { index }
+			}
+			_ => {
+				eprintln!("{}", tr!("ctl-usage"));
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let response = control::send(&request)?;
+
+		if let Some(error) = response.error {
+			eprintln!("{error}");
+			return Err(CommandError::Rejected);
+		}
+
+		match request {
+			control::ControlRequest::List => {
+				if response.entries.is_empty() {
+					outln!("{}", tr!("nothing-to-list"));
+				}
+
+				for (index, entry) in response.entries.iter().enumerate() {
+					outln!("{index} - {}: {}", entry.name, entry.description);
+				}
+			}
+			control::ControlRequest::Add { name, .. } => outln!("{}", tr!("entry-added", name = name)),
+			control::ControlRequest::Remove { index } => outln!("{}", tr!("entry-removed-at", index = index)),
+		}
+
+		Ok(())
+	}
+
+	#[cfg(not(unix))]
+	fn execute(&self, _state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		eprintln!("{}", tr!("ctl-unsupported"));
+
+		Err(CommandError::Rejected)
+	}
+}
+
+impl CommandHandler for Mcp {
+	fn key(&self) -> &'static str {
+		"mcp"
+	}
+
+	fn name(&self) -> &'static str {
+		"MCP"
+	}
+
+	fn description(&self) -> String {
+		tr!("mcp-description")
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		if !is_interactive() {
+			eprintln!("{}", tr!("input-required", command = self.key()));
+			return Err(CommandError::Rejected);
+		}
+
+		mcp::run(state);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Import {
+	fn key(&self) -> &'static str {
+		"import"
+	}
+
+	fn name(&self) -> &'static str {
+		"Import"
+	}
+
+	fn description(&self) -> String {
+		tr!("import-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[format] <path>|all <path> [--with-hooks]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let first = argument_or_prompt(arguments.next(), &tr!("prompt-import-format"));
+		let rest = arguments.collect::<Vec<&str>>().join(" ");
+
+		if first.eq_ignore_ascii_case("all") {
+			let with_hooks = rest.split_whitespace().any(|argument| argument == "--with-hooks");
+			let rest = rest.split_whitespace()
+				.filter(|argument| *argument != "--with-hooks")
+				.collect::<Vec<&str>>()
+				.join(" ");
+			let path = if rest.is_empty() { argument_or_prompt(None, &tr!("prompt-import-path")) } else { rest };
+
+			return import_all(state, &path, with_hooks);
+		}
+
+		let (format, path) = if IMPORT_FORMATS.contains(&first.to_lowercase().as_str()) {
+			let path = if rest.is_empty() { argument_or_prompt(None, &tr!("prompt-import-path")) } else { rest };
+
+			(Some(first.to_lowercase()), path)
+		} else if rest.is_empty() {
+			(None, first)
+		} else {
+			(None, format!("{first} {rest}"))
+		};
+
+		let data = match std::fs::read_to_string(&path) {
+			Ok(data) => data,
+			Err(_) if format.as_deref().is_none_or(|format| format == "maildir") && Path::new(&path).is_dir() => {
+				String::new()
+			}
+			Err(_) => {
+				eprintln!("{}", tr!("file-missing", path = path.as_str()));
+				return Err(CommandError::NotFound);
+			}
+		};
+
+		let detected = format.is_none();
+
+		let format = match format {
+			Some(format) => format,
+			None => {
+				let Some(format) = import::detect(Path::new(&path), &data) else {
+					eprintln!("{}", tr!("import-format-undetected", path = path.as_str()));
+					return Err(CommandError::InvalidInput);
+				};
+
+				outln!("{}", tr!("import-format-detected", format = format, path = path.as_str()));
+				format.to_string()
+			}
+		};
+
+		let imported = match format.as_str() {
+			"ron" => import::ron(&data),
+			"json" => import::json(&data),
+			"csv" => import::csv(&data),
+			"todo-txt" => Ok(import::todo_txt(&data)),
+			"markdown" => Ok(import::markdown(&data)),
+			"maildir" => import::maildir(Path::new(&path)),
+			"taskwarrior" => import::taskwarrior(&data),
+			"org" => Ok(import::org(&data, state.config.zone())),
+			"trello" => import::trello(&data),
+			"google-tasks" => import::google_tasks(&data),
+			_ => {
+				eprintln!("{}", tr!("unknown-import-format", format = format.as_str()));
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let imported = match imported {
+			Ok(imported) => imported,
+			Err(error) => {
+				eprintln!("{error}");
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let count = imported.entries.len();
+
+		if detected && count > 0 {
+			for entry in &imported.entries {
+				outln!("+ {}: {}", entry.name, entry.description);
+			}
+
+			if !confirm(&tr!("confirm-import", count = count)) {
+				return Ok(());
+			}
+		}
+
+		state.import_entries(imported.entries);
+
+		outln!("{}", tr!("imported", count = count, path = path.as_str()));
+
+		if imported.skipped > 0 {
+			outln!("{}", tr!("import-skipped", count = imported.skipped));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Export {
+	fn key(&self) -> &'static str {
+		"export"
+	}
+
+	fn name(&self) -> &'static str {
+		"Export"
+	}
+
+	fn description(&self) -> String {
+		tr!("export-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<format> [path] [where <filter>] [--fields <fields>]|all <path>|--manifest-version <version> [path]"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let format = argument_or_prompt(arguments.next(), &tr!("prompt-export-format")).to_lowercase();
+		let arguments = arguments.collect::<Vec<&str>>();
+
+		if format == "all" {
+			return export_all(state, &arguments.join(" "));
+		}
+
+		if format == "--manifest-version" {
+			return export_manifest_version(state, &arguments);
+		}
+
+		let end = arguments.iter()
+			.position(|argument| *argument == "where" || argument.starts_with("--"))
+			.unwrap_or(arguments.len());
+		let path = arguments[..end].join(" ");
+
+		let filter = arguments.iter().position(|argument| *argument == "where").map(|position| {
+			Filter::parse(
+				&arguments[position + 1..].iter()
+					.take_while(|argument| !argument.starts_with("--"))
+					.copied()
+					.collect::<Vec<&str>>()
+					.join(" ")
+			)
+		});
+
+		let filter = match filter.transpose() {
+			Ok(filter) => filter,
+			Err(error) => {
+				eprintln!("{error}");
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let fields = match arguments.iter().position(|argument| *argument == "--fields") {
+			Some(position) => {
+				let names = arguments.get(position + 1).copied().unwrap_or_default();
+				let fields = names.split(',')
+					.filter(|name| !name.trim().is_empty())
+					.map(|name| export::Field::parse(name).ok_or(name))
+					.collect::<Result<Vec<export::Field>, &str>>();
+
+				match fields {
+					Ok(fields) if !fields.is_empty() => fields,
+					unknown => {
+						eprintln!(
+							"{}",
+							tr!(
+								"unknown-export-field",
+								field = unknown.err().unwrap_or(names),
+								available = export::Field::ALL.map(export::Field::name).join(", ")
+							)
+						);
+						return Err(CommandError::InvalidInput);
+					}
+				}
+			}
+			None => match format.as_str() {
+				"org" => vec![export::Field::Name, export::Field::Description, export::Field::Tracked],
+				"html" => vec![
+					export::Field::Index,
+					export::Field::Name,
+					export::Field::Description,
+					export::Field::Tracked,
+				],
+				_ => export::Field::ALL.to_vec(),
+			},
+		};
+
+		let selection = export::Selection {
+			entries: state.entries.iter()
+				.enumerate()
+				.filter(|(_, entry)| filter.as_ref().is_none_or(|filter| filter.matches(entry)))
+				.collect(),
+			fields,
+		};
+
+		let data = match format.as_str() {
+			"org" => Ok(export::org(state, &selection)),
+			"html" => Ok(export::html(state, &selection)),
+			"csv" => export::csv(&selection),
+			"json" => export::json(&selection),
+			_ => {
+				eprintln!("{}", tr!("unknown-export-format", format = format.as_str()));
+				return Err(CommandError::InvalidInput);
+			}
+		};
+
+		let data = match data {
+			Ok(data) => data,
+			Err(error) => {
+				tracing::warn!("Failed to export as {format}: {error}");
+				eprintln!("{}", tr!("export-failed", format = format.as_str()));
+				return Err(CommandError::Io);
+			}
+		};
+
+		let count = selection.entries.len();
+
+		if path.is_empty() {
+			out!("{data}");
+		} else if state.dry_run {
+			outln!("{}", tr!("dry-run-would-export", count = count, path = path.as_str()));
+		} else if std::fs::write(&path, data).is_ok() {
+			outln!("{}", tr!("exported", count = count, path = path.as_str()));
+		} else {
+			eprintln!("{}", tr!("export-write-failed", path = path.as_str()));
+			return Err(CommandError::Io);
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Vault {
+	fn key(&self) -> &'static str {
+		"vault"
+	}
+
+	fn name(&self) -> &'static str {
+		"Vault"
+	}
+
+	fn description(&self) -> String {
+		tr!("vault-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[path]"
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		let path = arguments.collect::<Vec<&str>>().join(" ");
+		let path = if path.is_empty() {
+			state.config.vault.clone().unwrap_or_else(|| {
+				argument_or_prompt(None, &tr!("prompt-vault-path"))
+			})
+		} else {
+			path
+		};
+
+		let found = match import::markdown_vault(&PathBuf::from(&path)) {
+			Ok(found) => found,
+			Err(error) => {
+				eprintln!("{error}");
+				return Err(CommandError::Io);
+			}
+		};
+
+		let total = found.len();
+		let mut imported = 0;
+
+		for entry in found {
+			let known = state.entries.iter().any(|existing| {
+				existing.name == entry.name
+					&& existing.source.as_ref().map(|source| &source.path)
+						== entry.source.as_ref().map(|source| &source.path)
+			});
+
+			if !known {
+				state.add_entry(entry);
+				imported += 1;
+			}
+		}
+
+		outln!(
+			"{}",
+			tr!("vault-imported", count = imported, path = path.as_str(), known = total - imported)
+		);
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Script {
+	fn key(&self) -> &'static str {
+		"script"
+	}
+
+	fn name(&self) -> &'static str {
+		"Script"
+	}
+
+	fn description(&self) -> String {
+		tr!("script-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"run <file>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		if arguments.next().is_none_or(|action| !action.eq_ignore_ascii_case("run")) {
+			eprintln!("{}", tr!("script-usage"));
+			return Err(CommandError::InvalidInput);
+		}
+
+		let mut path = arguments.collect::<Vec<&str>>().join(" ");
+
+		if path.is_empty() {
+			path = argument_or_prompt(None, &tr!("prompt-script-path"));
+		}
+
+		let Ok(source) = std::fs::read_to_string(&path) else {
+			eprintln!("{}", tr!("file-missing", path = path.as_str()));
+			return Err(CommandError::NotFound);
+		};
+
+		if let Err(error) = script::run(state, &source) {
+			eprintln!("{}", tr!("script-failed", error = error));
+			return Err(CommandError::InvalidInput);
+		}
+
+		Ok(())
+	}
+}
+
+fn export_all(state: &State, path: &str) -> Result<(), CommandError> {
+	let path = if path.is_empty() {
+		format!("todotracker-export-{}.zip", crate::now().format("%Y%m%d-%H%M%S"))
+	} else {
+		path.to_string()
+	};
+
+	if state.dry_run {
+		outln!("{}", tr!("dry-run-would-export", count = state.entries.len(), path = path.as_str()));
+		return Ok(());
+	}
+
+	match bundle::write(state, &path) {
+		Ok(files) => {
+			outln!("{}", tr!("exported-all", files = files.join(", "), path = path.as_str()));
+			Ok(())
+		}
+		Err(error) => {
+			tracing::warn!("Failed to write {path}: {error}");
+			eprintln!("{}", tr!("export-write-failed", path = path.as_str()));
+			Err(CommandError::Io)
+		}
+	}
+}
+
+fn export_manifest_version(state: &State, arguments: &[&str]) -> Result<(), CommandError> {
+	let Some(version) = arguments.first()
+		.and_then(|version| version.parse::<usize>().ok())
+		.filter(|version| (1..=STATE_MANIFEST_VERSION).contains(version))
+	else {
+		eprintln!("{}", tr!("invalid-manifest-version", latest = STATE_MANIFEST_VERSION));
+		return Err(CommandError::InvalidInput);
+	};
+
+	let path = arguments[1..].join(" ");
+
+	let data = match export::ron(state, version) {
+		Ok(data) => data,
+		Err(error) => {
+			tracing::warn!("Failed to export with manifest version {version}: {error}");
+			eprintln!("{}", tr!("export-failed", format = "RON"));
+			return Err(CommandError::Io);
+		}
+	};
+
+	if path.is_empty() {
+		out!("{data}");
+	} else if state.dry_run {
+		outln!("{}", tr!("dry-run-would-export", count = state.entries.len(), path = path.as_str()));
+	} else if std::fs::write(&path, data).is_ok() {
+		outln!("{}", tr!("exported", count = state.entries.len(), path = path.as_str()));
+	} else {
+		eprintln!("{}", tr!("export-write-failed", path = path.as_str()));
+		return Err(CommandError::Io);
+	}
+
+	Ok(())
+}
+
+fn import_all(state: &mut State, path: &str, with_hooks: bool) -> Result<(), CommandError> {
+	let mut files = match bundle::read(path) {
+		Ok(files) => files,
+		Err(error) => {
+			tracing::warn!("Failed to read {path}: {error}");
+			eprintln!("{}", tr!("import-all-unreadable", path = path));
+			return Err(CommandError::InvalidInput);
+		}
+	};
+
+	let names = files.iter().map(|(name, _)| *name).collect::<Vec<&str>>().join(", ");
+
+	let Some(imported) = files.iter()
+		.find(|(name, _)| *name == STATE_FILE_PATH)
+		.and_then(|(_, data)| ron::from_str::<State>(&String::from_utf8_lossy(data)).ok())
+	else {
+		eprintln!("{}", tr!("import-all-unreadable", path = path));
+		return Err(CommandError::InvalidInput);
+	};
+
+	if let Some((_, data)) = files.iter_mut().find(|(name, _)| *name == CONFIG_FILE_PATH) {
+		let Ok(mut config) = ron::from_str::<Config>(&String::from_utf8_lossy(data)) else {
+			eprintln!("{}", tr!("import-all-unreadable", path = path));
+			return Err(CommandError::InvalidInput);
+		};
+
+		let commands = bundled_commands(&config);
+
+		if !commands.is_empty() && with_hooks {
+			outln!("{}", tr!("import-all-commands", path = CONFIG_FILE_PATH));
+
+			for command in &commands {
+				outln!("  {command}");
+			}
+
+			if !confirm(&tr!("confirm-import-all-commands")) {
+				return Ok(());
+			}
+		} else if !commands.is_empty() {
+			config.hooks = state.config.hooks.clone();
+			config.schedules = state.config.schedules.clone();
+			config.webhooks = state.config.webhooks.clone();
+			config.git_sync = state.config.git_sync;
+
+			match ron::ser::to_string_pretty(&config, ron::ser::PrettyConfig::default()) {
+				Ok(config) => *data = config.into_bytes(),
+				Err(error) => {
+					tracing::warn!("Failed to serialize the configuration: {error}");
+					eprintln!("{}", tr!("config-serialize-failed"));
+					return Err(CommandError::Io);
+				}
+			}
+
+			outln!("{}", tr!("import-all-commands-skipped", count = commands.len(), path = CONFIG_FILE_PATH));
+		}
+	}
+
+	if !confirm(&tr!("confirm-import-all", files = names.as_str())) {
+		return Ok(());
+	}
+
+	if state.dry_run {
+		outln!("{}", tr!("dry-run-would-import-all", files = names.as_str()));
+	} else {
+		for (name, data) in &files {
+			if let Err(error) = std::fs::write(name, data) {
+				tracing::warn!("Failed to write {name}: {error}");
+				eprintln!("{}", tr!("import-all-write-failed", path = *name));
+				return Err(CommandError::Io);
+			}
+		}
+
+		state.config = Config::load();
+		state.command_history = history::load();
+	}
+
+	state.entries = imported.entries;
+	state.pomodoros = imported.pomodoros;
+	state.focus = imported.focus;
+	state.planned = imported.planned;
+	state.last_id = state.last_id.max(imported.last_id);
+	state.selection.clear();
+	state.assign_missing_ids();
+	state.modified = state.dry_run;
+
+	outln!("{}", tr!("imported-all", files = names.as_str(), path = path));
+
+	Ok(())
+}
+
+fn bundled_commands(config: &Config) -> Vec<String> {
+	let hooks = [
+		("on_add", &config.hooks.on_add),
+		("on_save", &config.hooks.on_save),
+		("on_load", &config.hooks.on_load),
+	];
+
+	hooks.into_iter()
+		.filter_map(|(name, hook)| Some(format!("{name}: {}", hook.as_ref()?)))
+		.chain(config.schedules.iter().map(|schedule| format!("{}: {}", schedule.cron, schedule.command)))
+		.chain(config.webhooks.iter().map(|webhook| format!("webhook: {webhook}")))
+		.chain(config.git_sync.then(|| "git_sync: true".to_string()))
+		.collect()
+}
@@ -0,0 +1,92 @@
+use super::{Arguments, CommandError, CommandHandler};
+use crate::locale::tr;
+use crate::output::outln;
+use crate::{run_line, write_config_file, State};
+
+pub struct Macro;
+
+impl CommandHandler for Macro {
+	fn key(&self) -> &'static str {
+		"macro"
+	}
+
+	fn name(&self) -> &'static str {
+		"Macro"
+	}
+
+	fn description(&self) -> String {
+		tr!("macro-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[list|record <name>|stop|run <name>]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let action = arguments.next().unwrap_or("list").to_lowercase();
+		let name = arguments.collect::<Vec<&str>>().join(" ");
+
+		match action.as_str() {
+			"list" => {
+				if state.config.macros.is_empty() {
+					outln!("{}", tr!("no-macros"));
+				}
+
+				for (name, commands) in &state.config.macros {
+					outln!("{name}: {}", commands.join("; "));
+				}
+
+				Ok(())
+			}
+			"record" if !name.is_empty() => {
+				if let Some((recording, _)) = &state.macro_recording {
+					eprintln!("{}", tr!("macro-already-recording", name = recording.as_str()));
+					return Err(CommandError::InvalidInput);
+				}
+
+				outln!("{}", tr!("macro-recording", name = name.as_str()));
+				state.macro_recording = Some((name, Vec::new()));
+
+				Ok(())
+			}
+			"stop" => {
+				let Some((name, commands)) = state.macro_recording.take() else {
+					eprintln!("{}", tr!("macro-not-recording"));
+					return Err(CommandError::InvalidInput);
+				};
+
+				if commands.is_empty() {
+					outln!("{}", tr!("macro-empty", name = name.as_str()));
+					return Ok(());
+				}
+
+				let count = commands.len();
+				state.config.macros.insert(name.clone(), commands);
+
+				if !write_config_file(state) {
+					return Err(CommandError::Io);
+				}
+
+				outln!("{}", tr!("macro-saved", name = name.as_str(), count = count));
+
+				Ok(())
+			}
+			"run" if !name.is_empty() => {
+				let Some(commands) = state.config.macros.get(&name).cloned() else {
+					eprintln!("{}", tr!("macro-not-found", name = name.as_str()));
+					return Err(CommandError::NotFound);
+				};
+
+				for command in commands.iter().filter(|command| !command.starts_with(self.key())) {
+					run_line(state, command)?;
+				}
+
+				Ok(())
+			}
+			_ => {
+				eprintln!("{}", tr!("macro-usage"));
+				Err(CommandError::InvalidInput)
+			}
+		}
+	}
+}
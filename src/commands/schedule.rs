@@ -0,0 +1,57 @@
+use super::{Arguments, CommandError, CommandHandler};
+use crate::locale::{self, tr};
+use crate::output::outln;
+use crate::schedule::Cron;
+use crate::State;
+
+pub struct Schedule;
+
+impl CommandHandler for Schedule {
+	fn key(&self) -> &'static str {
+		"schedule"
+	}
+
+	fn name(&self) -> &'static str {
+		"Schedule"
+	}
+
+	fn description(&self) -> String {
+		tr!("schedule-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"[list]"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		if !matches!(arguments.next(), None | Some("list")) {
+			eprintln!("{}", tr!("schedule-usage"));
+			return Err(CommandError::InvalidInput);
+		}
+
+		if state.config.schedules.is_empty() {
+			outln!("{}", tr!("no-schedules"));
+			return Ok(());
+		}
+
+		let now = crate::now();
+
+		for schedule in &state.config.schedules {
+			let next = match Cron::parse(&schedule.cron) {
+				Ok(cron) => cron.next_after(now, state.config.zone()).map_or_else(
+					|| tr!("schedule-never"),
+					|next| tr!("schedule-next", time = locale::format_timestamp(next, &state.config)),
+				),
+				Err(error) => error,
+			};
+
+			outln!("{} - {} ({next})", schedule.cron, schedule.command);
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,108 @@
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+
+use super::{Arguments, CommandError, CommandHandler};
+use crate::locale::tr;
+use crate::output::outln;
+use crate::{argument_or_prompt, colors_enabled, State};
+
+pub struct Find;
+
+struct SearchResult {
+	index: usize,
+	score: i64,
+	name: Vec<usize>,
+	description: Vec<usize>,
+}
+
+impl CommandHandler for Find {
+	fn key(&self) -> &'static str {
+		"find"
+	}
+
+	fn name(&self) -> &'static str {
+		"Find"
+	}
+
+	fn description(&self) -> String {
+		tr!("find-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<text>"
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, arguments: Arguments) -> Result<(), CommandError> {
+		let query = arguments.collect::<Vec<&str>>().join(" ");
+		let query = argument_or_prompt(Some(query.as_str()).filter(|query| !query.is_empty()), &tr!("prompt-find-query"));
+
+		let results = search(state, &query);
+
+		if results.is_empty() {
+			outln!("{}", tr!("nothing-found", query = query.as_str()));
+			return Ok(());
+		}
+
+		let highlight = colors_enabled();
+
+		for (number, result) in results.iter().enumerate() {
+			let entry = &state.entries[result.index];
+
+			if highlight {
+				outln!(
+					"#{} {} - {}: {}",
+					number + 1,
+					result.index,
+					highlighted(&entry.name, &result.name),
+					highlighted(&entry.description, &result.description)
+				);
+			} else {
+				outln!("#{} {} - {}: {}", number + 1, result.index, entry.name, entry.description);
+			}
+		}
+
+		state.selection = results.iter().map(|result| state.entries[result.index].id).collect();
+
+		Ok(())
+	}
+}
+
+fn search(state: &State, query: &str) -> Vec<SearchResult> {
+	let matcher = SkimMatcherV2::default();
+
+	let mut results = state.entries.iter()
+		.enumerate()
+		.filter_map(|(index, entry)| {
+			let name = matcher.fuzzy_indices(&entry.name, query);
+			let description = matcher.fuzzy_indices(&entry.description, query);
+			let score = name.iter().chain(&description).map(|(score, _)| *score).max()?;
+
+			Some(SearchResult {
+				index,
+				score,
+				name: name.map(|(_, indices)| indices).unwrap_or_default(),
+				description: description.map(|(_, indices)| indices).unwrap_or_default(),
+			})
+		})
+		.collect::<Vec<SearchResult>>();
+
+	results.sort_by_key(|result| std::cmp::Reverse(result.score));
+	results
+}
+
+fn highlighted(text: &str, indices: &[usize]) -> String {
+	text.chars()
+		.enumerate()
+		.map(|(index, character)| {
+			if indices.contains(&index) {
+				format!("\x1b[1m{character}\x1b[0m")
+			} else {
+				character.to_string()
+			}
+		})
+		.collect()
+}
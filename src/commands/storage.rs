@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+use super::{Arguments, CommandError, CommandHandler};
+use crate::locale::tr;
+use crate::output::outln;
+use crate::{confirm, git_sync, hooks, parse_state, write_state_file, State, STATE_FILE_PATH};
+
+pub struct Save;
+pub struct Load;
+
+impl CommandHandler for Save {
+	fn key(&self) -> &'static str {
+		"save"
+	}
+
+	fn name(&self) -> &'static str {
+		"Save"
+	}
+
+	fn description(&self) -> String {
+		tr!("save-description")
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		let saved = PathBuf::from(STATE_FILE_PATH).exists();
+
+		if !state.modified && saved {
+			outln!("{}", tr!("nothing-changed", path = STATE_FILE_PATH));
+			return Ok(());
+		}
+
+		if state.entries.is_empty() {
+			if !saved {
+				outln!("{}", tr!("nothing-to-save"));
+				return Ok(());
+			}
+
+			if !confirm(&tr!("confirm-save-empty", path = STATE_FILE_PATH)) {
+				return Ok(());
+			}
+		}
+
+		if state.stale && !confirm(&tr!("confirm-overwrite-changed", path = STATE_FILE_PATH)) {
+			return Ok(());
+		}
+
+		if !write_state_file(state) {
+			return Err(CommandError::Io);
+		}
+
+		if !state.dry_run {
+			state.modified = false;
+			state.stale = false;
+			outln!("{}", tr!("state-saved", path = STATE_FILE_PATH));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Load {
+	fn key(&self) -> &'static str {
+		"load"
+	}
+
+	fn name(&self) -> &'static str {
+		"Load"
+	}
+
+	fn description(&self) -> String {
+		tr!("load-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		if state.config.git_sync && !state.dry_run && !state.read_only {
+			git_sync::pull();
+		}
+
+		if PathBuf::from(STATE_FILE_PATH).exists() {
+			let data = std::fs::read_to_string(STATE_FILE_PATH)
+				.map_err(|error| {
+					(tr!("state-read-failed-path", path = STATE_FILE_PATH, error = error.to_string()), CommandError::Io)
+				})
+				.and_then(|text| {
+					parse_state(&text, STATE_FILE_PATH).map_err(|error| (error, CommandError::InvalidInput))
+				});
+
+			let data = match data {
+				Ok(data) => data,
+				Err((error, command_error)) => {
+					tracing::warn!("Failed to load {STATE_FILE_PATH}: {error}");
+					eprintln!("{error}");
+					return Err(command_error);
+				}
+			};
+
+			tracing::debug!(
+				"{STATE_FILE_PATH} has manifest version {}, expected {}",
+				data.manifest_version,
+				state.manifest_version
+			);
+
+			match data.manifest_version.cmp(&state.manifest_version) {
+				Ordering::Less => {
+					eprintln!("{}", tr!("manifest-older"));
+				}
+				Ordering::Greater => {
+					eprintln!("{}", tr!("manifest-newer"));
+				}
+				Ordering::Equal => {}
+			}
+
+			if data.entries != state.entries && !state.entries.is_empty() && !confirm(&tr!("confirm-override")) {
+				return Ok(());
+			}
+
+			state.entries = data.entries;
+			state.pomodoros = data.pomodoros;
+			state.focus = data.focus;
+			state.planned = data.planned;
+			state.last_id = state.last_id.max(data.last_id);
+			state.selection.clear();
+			state.assign_missing_ids();
+			state.modified = false;
+			state.stale = false;
+			if !state.dry_run {
+				hooks::run(state.config.hooks.on_load.as_ref(), "on_load", &state.entries);
+			}
+
+			outln!("{}", tr!("state-loaded", count = state.entries.len()));
+		} else {
+			eprintln!("{}", tr!("state-missing"));
+			return Err(CommandError::NotFound);
+		}
+
+		Ok(())
+	}
+}
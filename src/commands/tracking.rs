@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use chrono::{NaiveDate, Utc};
+
+use super::{Arguments, CommandError, CommandHandler};
+use crate::locale::{self, tr};
+use crate::output::{out, outln};
+use crate::{
+	argument_or_prompt, format_duration, parse_duration, State, TimeInterval,
+	DEFAULT_POMODORO_MINUTES,
+};
+
+pub struct Start;
+pub struct Stop;
+pub struct Timesheet;
+pub struct Pomodoro;
+
+impl CommandHandler for Start {
+	fn key(&self) -> &'static str {
+		"start"
+	}
+
+	fn name(&self) -> &'static str {
+		"Start"
+	}
+
+	fn description(&self) -> String {
+		tr!("start-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index>"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-track-index"))
+		)?;
+
+		if index >= state.entries.len() {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		}
+
+		if state.entries[index].tracking_since.is_some() {
+			outln!("{}", tr!("already-tracking", name = state.entries[index].name.as_str()));
+			return Ok(());
+		}
+
+		let now = crate::now();
+
+		for entry in &mut state.entries {
+			if let Some(duration) = entry.stop_tracking(now) {
+				outln!(
+					"{}",
+					tr!("tracking-stopped", name = entry.name.as_str(), duration = format_duration(duration))
+				);
+			}
+		}
+
+		state.entries[index].tracking_since = Some(now);
+		outln!("{}", tr!("tracking-started", name = state.entries[index].name.as_str()));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Stop {
+	fn key(&self) -> &'static str {
+		"stop"
+	}
+
+	fn name(&self) -> &'static str {
+		"Stop"
+	}
+
+	fn description(&self) -> String {
+		tr!("stop-description")
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		let now = crate::now();
+		let mut stopped = false;
+
+		for entry in &mut state.entries {
+			if let Some(duration) = entry.stop_tracking(now) {
+				outln!(
+					"{}",
+					tr!("tracking-stopped", name = entry.name.as_str(), duration = format_duration(duration))
+				);
+				stopped = true;
+			}
+		}
+
+		if !stopped {
+			outln!("{}", tr!("not-tracking"));
+		}
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Timesheet {
+	fn key(&self) -> &'static str {
+		"timesheet"
+	}
+
+	fn name(&self) -> &'static str {
+		"Timesheet"
+	}
+
+	fn description(&self) -> String {
+		tr!("timesheet-description")
+	}
+
+	fn read_only(&self) -> bool {
+		true
+	}
+
+	fn execute(&self, state: &mut State, _arguments: Arguments) -> Result<(), CommandError> {
+		let now = crate::now();
+		let mut days = BTreeMap::<NaiveDate, chrono::Duration>::new();
+		let mut total = chrono::Duration::zero();
+
+		for (index, entry) in state.entries.iter().enumerate() {
+			let tracked = entry.tracked_time(now);
+
+			if tracked.is_zero() {
+				continue;
+			}
+
+			if total.is_zero() {
+				outln!("{}", tr!("timesheet-per-entry"));
+			}
+
+			outln!("{index} - {}: {}", entry.name, format_duration(tracked));
+			total += tracked;
+
+			for interval in entry.intervals(now) {
+				*days.entry(interval.day(state.config.zone())).or_insert_with(chrono::Duration::zero) += interval.duration();
+			}
+		}
+
+		if total.is_zero() {
+			outln!("{}", tr!("timesheet-empty"));
+			return Ok(());
+		}
+
+		outln!("{}", tr!("timesheet-per-day"));
+
+		for (day, duration) in days {
+			outln!("{}: {}", locale::format_date(day), format_duration(duration));
+		}
+
+		outln!("{}", tr!("timesheet-total", duration = format_duration(total)));
+
+		Ok(())
+	}
+}
+
+impl CommandHandler for Pomodoro {
+	fn key(&self) -> &'static str {
+		"pomodoro"
+	}
+
+	fn name(&self) -> &'static str {
+		"Pomodoro"
+	}
+
+	fn description(&self) -> String {
+		tr!("pomodoro-description")
+	}
+
+	fn usage(&self) -> &'static str {
+		"<index> [duration]"
+	}
+
+	fn execute(&self, state: &mut State, mut arguments: Arguments) -> Result<(), CommandError> {
+		let index = state.resolve_index(
+			&argument_or_prompt(arguments.next(), &tr!("prompt-pomodoro-index"))
+		)?;
+
+		let Some(length) = arguments.next()
+			.map_or(Some(chrono::Duration::minutes(DEFAULT_POMODORO_MINUTES)), parse_duration)
+		else {
+			eprintln!("{}", tr!("invalid-duration"));
+			return Err(CommandError::InvalidInput);
+		};
+
+		if index >= state.entries.len() {
+			eprintln!("{}", tr!("entry-not-found", index = index));
+			return Err(CommandError::NotFound);
+		}
+
+		let start = crate::now();
+
+		for entry in &mut state.entries {
+			if let Some(duration) = entry.stop_tracking(start) {
+				outln!(
+					"{}",
+					tr!("tracking-stopped", name = entry.name.as_str(), duration = format_duration(duration))
+				);
+			}
+		}
+
+		let end = Utc::now() + length;
+		let name = state.entries[index].name.clone();
+
+		outln!("{}", tr!("pomodoro-started", name = name.as_str()));
+
+		loop {
+			let remaining = end - Utc::now();
+
+			if remaining <= chrono::Duration::zero() {
+				break;
+			}
+
+			out!(
+				"\r{}",
+				tr!(
+					"pomodoro-remaining",
+					time = format!("{:02}:{:02}", remaining.num_minutes(), remaining.num_seconds() % 60)
+				)
+			);
+			std::io::stdout().flush().unwrap_or_default();
+
+			std::thread::sleep(
+				remaining.min(chrono::Duration::seconds(1)).to_std().unwrap_or_default()
+			);
+		}
+
+		state.entries[index].time_log.push(TimeInterval { start, end: start + length });
+		state.pomodoros.record(state.config.zone().today());
+
+		out!("\r{:20}\r", "");
+		outln!("\x07{}", tr!("pomodoro-finished", name = name.as_str()));
+		outln!(
+			"{}",
+			tr!(
+				"pomodoro-stats",
+				completed = state.pomodoros.completed,
+				streak = state.pomodoros.streak,
+				best = state.pomodoros.best_streak
+			)
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,168 @@
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::CommandError;
+use crate::locale::tr;
+use crate::output::outln;
+use crate::{State, TodoEntry};
+
+const CONTROL_SOCKET_PATH: &str = "todotracker.sock";
+const CONTROL_TIMEOUT: Duration = Duration::from_secs(5);
+
+static LISTENING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlRequest {
+	List,
+	Add {
+		name: String,
+		#[serde(default)]
+		description: String,
+	},
+	Remove {
+		index: usize,
+	},
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ControlResponse {
+	pub ok: bool,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub entries: Vec<TodoEntry>,
+}
+
+pub struct ControlSocket;
+
+pub fn listen(state: Arc<Mutex<State>>) -> Option<ControlSocket> {
+	if Path::new(CONTROL_SOCKET_PATH).exists() {
+		if UnixStream::connect(CONTROL_SOCKET_PATH).is_ok() {
+			eprintln!("{}", tr!("control-socket-in-use", path = CONTROL_SOCKET_PATH));
+			return None;
+		}
+
+		std::fs::remove_file(CONTROL_SOCKET_PATH).unwrap_or_default();
+	}
+
+	let listener = UnixListener::bind(CONTROL_SOCKET_PATH).map_err(|_| {
+		eprintln!("{}", tr!("control-socket-failed", path = CONTROL_SOCKET_PATH));
+	}).ok()?;
+
+	std::thread::spawn(move || {
+		for stream in listener.incoming().flatten() {
+			serve_connection(stream, &state);
+		}
+	});
+
+	LISTENING.store(true, Ordering::Relaxed);
+
+	Some(ControlSocket)
+}
+
+pub fn is_listening() -> bool {
+	LISTENING.load(Ordering::Relaxed)
+}
+
+pub fn send(request: &ControlRequest) -> Result<ControlResponse, CommandError> {
+	let Ok(mut stream) = UnixStream::connect(CONTROL_SOCKET_PATH) else {
+		eprintln!("{}", tr!("ctl-no-session"));
+		return Err(CommandError::NotFound);
+	};
+
+	stream.set_read_timeout(Some(CONTROL_TIMEOUT)).unwrap_or_default();
+	stream.set_write_timeout(Some(CONTROL_TIMEOUT)).unwrap_or_default();
+
+	let data = serde_json::to_string(request).unwrap_or_default();
+	let mut line = String::new();
+
+	if writeln!(stream, "{data}").is_err() || BufReader::new(stream).read_line(&mut line).is_err() {
+		eprintln!("{}", tr!("ctl-timeout"));
+		return Err(CommandError::Io);
+	}
+
+	serde_json::from_str(&line).map_err(|_| {
+		eprintln!("{}", tr!("ctl-no-session"));
+		CommandError::NotFound
+	})
+}
+
+fn serve_connection(stream: UnixStream, state: &Mutex<State>) {
+	let Ok(mut writer) = stream.try_clone() else {
+		eprintln!("{}", tr!("control-connection-failed"));
+		return;
+	};
+
+	for line in BufReader::new(stream).lines().map_while(Result::ok) {
+		let response = match serde_json::from_str::<ControlRequest>(&line) {
+			Ok(request) => apply(request, &mut state.lock().unwrap_or_else(PoisonError::into_inner)),
+			Err(error) => ControlResponse::error(format!("Invalid request: {error}")),
+		};
+
+		let data = serde_json::to_string(&response).unwrap_or_default();
+
+		if writeln!(writer, "{data}").is_err() {
+			break;
+		}
+	}
+}
+
+pub fn apply(request: ControlRequest, state: &mut State) -> ControlResponse {
+	if state.read_only && !matches!(request, ControlRequest::List) {
+		return ControlResponse::error("The session is in read-only mode".to_string());
+	}
+
+	match request {
+		ControlRequest::List => ControlResponse {
+			entries: state.entries.clone(),
+			..ControlResponse::success()
+		},
+		ControlRequest::Add { name, description } => {
+			outln!("{}", tr!("control-added", name = name.as_str()));
+			state.add_entry(TodoEntry::new(name, description));
+
+			ControlResponse::success()
+		}
+		ControlRequest::Remove { index } => {
+			if let Some(entry) = state.remove_entry(index) {
+				outln!("{}", tr!("control-removed", name = entry.name.as_str()));
+
+				ControlResponse::success()
+			} else {
+				ControlResponse::error(format!("No todo entry found at index {index}"))
+			}
+		}
+	}
+}
+
+impl ControlResponse {
+	fn success() -> Self {
+		ControlResponse {
+			ok: true,
+			error: None,
+			entries: Vec::new(),
+		}
+	}
+
+	fn error(message: String) -> Self {
+		ControlResponse {
+			ok: false,
+			error: Some(message),
+			entries: Vec::new(),
+		}
+	}
+}
+
+impl Drop for ControlSocket {
+	fn drop(&mut self) {
+		LISTENING.store(false, Ordering::Relaxed);
+		std::fs::remove_file(CONTROL_SOCKET_PATH).unwrap_or_default();
+	}
+}
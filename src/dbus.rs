@@ -0,0 +1,56 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use zbus::blocking::connection::{Builder, Connection};
+use zbus::{fdo, interface};
+
+use crate::control::{self, ControlRequest, ControlResponse};
+use crate::locale::tr;
+use crate::State;
+
+const BUS_NAME: &str = "io.github.Theboiboi8.TodoTracker";
+const OBJECT_PATH: &str = "/io/github/Theboiboi8/TodoTracker";
+
+struct Tracker {
+	state: Arc<Mutex<State>>,
+}
+
+#[interface(name = "io.github.Theboiboi8.TodoTracker1")]
+impl Tracker {
+	fn list(&self) -> fdo::Result<Vec<(String, String)>> {
+		let response = self.apply(ControlRequest::List)?;
+
+		Ok(response.entries.into_iter().map(|entry| (entry.name, entry.description)).collect())
+	}
+
+	fn add(&self, name: String, description: String) -> fdo::Result<()> {
+		self.apply(ControlRequest::Add { name, description }).map(|_| ())
+	}
+
+	fn remove(&self, index: u32) -> fdo::Result<()> {
+		self.apply(ControlRequest::Remove { index: index as usize }).map(|_| ())
+	}
+}
+
+impl Tracker {
+	fn apply(&self, request: ControlRequest) -> fdo::Result<ControlResponse> {
+		let response = control::apply(request, &mut self.state.lock().unwrap_or_else(PoisonError::into_inner));
+
+		if response.ok {
+			Ok(response)
+		} else {
+			Err(fdo::Error::Failed(response.error.unwrap_or_default()))
+		}
+	}
+}
+
+pub fn serve(state: Arc<Mutex<State>>) -> Option<Connection> {
+	Builder::session()
+		.and_then(|builder| builder.name(BUS_NAME))
+		.and_then(|builder| builder.serve_at(OBJECT_PATH, Tracker { state }))
+		.and_then(Builder::build)
+		.map_err(|error| {
+			tracing::warn!("Failed to register {BUS_NAME} on the session bus: {error}");
+			eprintln!("{}", tr!("dbus-failed", name = BUS_NAME));
+		})
+		.ok()
+}
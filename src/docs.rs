@@ -0,0 +1,79 @@
+use crate::commands::CommandRegistry;
+use crate::locale::{self, tr};
+use crate::{CONFIG_FILE_PATH, STATE_FILE_PATH};
+
+const OPTIONS: [(&str, &str); 9] = [
+	("-h, --help", "option-help"),
+	("-q, --quiet", "option-quiet"),
+	("-v, -vv, -vvv, --verbose", "option-verbose"),
+	("--log-file <path>", "option-log-file"),
+	("--dry-run", "option-dry-run"),
+	("--read-only", "option-read-only"),
+	("--sandbox", "option-sandbox"),
+	("--deterministic", "option-deterministic"),
+	("--seed <number>", "option-seed"),
+];
+
+pub fn help() -> String {
+	let mut text = format!("{}\n\n{}\n\n{}\n", tr!("title"), tr!("docs-usage"), tr!("docs-options"));
+
+	for (flag, key) in OPTIONS {
+		text += &format!("  {flag:<26} {}\n", locale::text(key, None));
+	}
+
+	text += &format!("\n{}\n", tr!("docs-commands"));
+
+	for command in CommandRegistry::builtin().iter() {
+		let synopsis = format!("{} {}", command.key(), command.usage());
+
+		if synopsis.trim_end().len() > 26 {
+			text += &format!("  {}\n  {:<26} {}\n", synopsis.trim_end(), "", command.description());
+		} else {
+			text += &format!("  {:<26} {}\n", synopsis.trim_end(), command.description());
+		}
+	}
+
+	text += &format!("\n{}\n", tr!("docs-files", state = STATE_FILE_PATH, config = CONFIG_FILE_PATH));
+	text
+}
+
+pub fn man() -> String {
+	let mut page = format!(
+		".TH TODOTRACKER 1 \"\" \"todotracker {}\"\n.SH NAME\ntodotracker \\- {}\n.SH SYNOPSIS\n.B todotracker\n[\\fIOPTIONS\\fR] [\\fICOMMAND\\fR [\\fIARGUMENTS\\fR]]\n.SH DESCRIPTION\n{}\n.SH OPTIONS\n",
+		env!("CARGO_PKG_VERSION"),
+		escape(&tr!("docs-summary")),
+		escape(&tr!("docs-about")),
+	);
+
+	for (flag, key) in OPTIONS {
+		page += &format!(".TP\n.B {}\n{}\n", escape(flag), escape(&locale::text(key, None)));
+	}
+
+	page += ".SH COMMANDS\n";
+
+	for command in CommandRegistry::builtin().iter() {
+		page += &format!(
+			".TP\n\\fB{}\\fR {}\n{}\n",
+			escape(command.key()),
+			escape(command.usage()),
+			escape(&command.description())
+		);
+	}
+
+	page += &format!(
+		".SH FILES\n.TP\n.I {STATE_FILE_PATH}\n{}\n.TP\n.I {CONFIG_FILE_PATH}\n{}\n",
+		escape(&tr!("docs-state-file")),
+		escape(&tr!("docs-config-file")),
+	);
+	page
+}
+
+fn escape(text: &str) -> String {
+	let escaped = text.replace('\\', "\\e").replace('-', "\\-");
+
+	if escaped.starts_with(['.', '\'']) {
+		format!("\\&{escaped}")
+	} else {
+		escaped
+	}
+}
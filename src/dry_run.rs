@@ -0,0 +1,62 @@
+use crate::locale::tr;
+use crate::{State, TodoEntry};
+
+pub fn report(before: &State, after: &State) {
+	let mut removed = before.entries.iter().collect::<Vec<&TodoEntry>>();
+	let mut added = Vec::<(usize, &TodoEntry)>::new();
+
+	for (index, entry) in after.entries.iter().enumerate() {
+		if let Some(position) = removed.iter().position(|existing| *existing == entry) {
+			removed.remove(position);
+		} else {
+			added.push((index, entry));
+		}
+	}
+
+	let mut modified = Vec::<(usize, &TodoEntry)>::new();
+
+	added.retain(|(index, entry)| {
+		let Some(position) = removed.iter().position(|existing| existing.name == entry.name) else {
+			return true;
+		};
+
+		removed.remove(position);
+		modified.push((*index, entry));
+		false
+	});
+
+	let reordered = removed.is_empty() && added.is_empty() && modified.is_empty()
+		&& before.entries != after.entries;
+
+	if removed.is_empty() && added.is_empty() && modified.is_empty() && !reordered
+		&& before.focus == after.focus && before.planned == after.planned && before.pomodoros == after.pomodoros
+	{
+		return;
+	}
+
+	eprintln!("{}", tr!("dry-run-header"));
+
+	for (index, entry) in added {
+		eprintln!("+ {index} - {}: {}", entry.name, entry.description);
+	}
+
+	for entry in removed {
+		eprintln!("- {}: {}", entry.name, entry.description);
+	}
+
+	for (index, entry) in modified {
+		eprintln!("~ {index} - {}: {}", entry.name, entry.description);
+	}
+
+	if reordered {
+		eprintln!("~ {}", tr!("dry-run-reordered"));
+	}
+
+	if before.focus != after.focus || before.planned != after.planned {
+		eprintln!("~ {}", tr!("dry-run-focus-changed"));
+	}
+
+	if before.pomodoros != after.pomodoros {
+		eprintln!("~ {}", tr!("dry-run-pomodoros-updated"));
+	}
+}
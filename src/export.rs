@@ -0,0 +1,251 @@
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+
+use crate::locale::{self, tr};
+use crate::timezone::Zone;
+use crate::{format_duration, State, TimeInterval, TodoEntry, WRITTEN_MANIFEST_VERSION};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Field {
+	Index,
+	Name,
+	Description,
+	Tracked,
+	Estimate,
+	Assignee,
+	Location,
+	Color,
+}
+
+pub struct Selection<'a> {
+	pub entries: Vec<(usize, &'a TodoEntry)>,
+	pub fields: Vec<Field>,
+}
+
+impl Field {
+	pub const ALL: [Field; 8] = [
+		Field::Index,
+		Field::Name,
+		Field::Description,
+		Field::Tracked,
+		Field::Estimate,
+		Field::Assignee,
+		Field::Location,
+		Field::Color,
+	];
+
+	pub fn parse(name: &str) -> Option<Self> {
+		Field::ALL.into_iter().find(|field| field.name().eq_ignore_ascii_case(name.trim()))
+	}
+
+	pub fn name(self) -> &'static str {
+		match self {
+			Field::Index => "index",
+			Field::Name => "name",
+			Field::Description => "description",
+			Field::Tracked => "tracked",
+			Field::Estimate => "estimate",
+			Field::Assignee => "assignee",
+			Field::Location => "location",
+			Field::Color => "color",
+		}
+	}
+
+	fn heading(self) -> String {
+		match self {
+			Field::Index => "#".to_string(),
+			Field::Name => tr!("export-heading-name"),
+			Field::Description => tr!("export-heading-description"),
+			Field::Tracked => tr!("export-heading-tracked"),
+			Field::Estimate => tr!("export-heading-estimate"),
+			Field::Assignee => tr!("export-heading-assignee"),
+			Field::Location => tr!("export-heading-location"),
+			Field::Color => tr!("export-heading-color"),
+		}
+	}
+
+	fn value(self, index: usize, entry: &TodoEntry, now: DateTime<Utc>) -> String {
+		match self {
+			Field::Index => index.to_string(),
+			Field::Name => entry.name.clone(),
+			Field::Description => entry.description.clone(),
+			Field::Tracked => {
+				let tracked = entry.tracked_time(now);
+
+				if tracked.is_zero() {
+					String::new()
+				} else {
+					format_duration(tracked)
+				}
+			}
+			Field::Estimate => entry.estimate().map(format_duration).unwrap_or_default(),
+			Field::Assignee => entry.assignee.clone().unwrap_or_default(),
+			Field::Location => entry.location.clone().unwrap_or_default(),
+			Field::Color => entry.color.map(|color| color.name().to_string()).unwrap_or_default(),
+		}
+	}
+}
+
+pub fn ron(state: &State, manifest_version: usize) -> Result<String, String> {
+	let mut state = state.clone();
+	state.manifest_version = manifest_version;
+
+	let previous = WRITTEN_MANIFEST_VERSION.replace(manifest_version);
+	let data = ron::ser::to_string_pretty(&state, ron::ser::PrettyConfig::default());
+	WRITTEN_MANIFEST_VERSION.set(previous);
+
+	data.map(|data| data + "\n").map_err(|error| error.to_string())
+}
+
+pub fn org(state: &State, selection: &Selection) -> String {
+	let now = crate::now();
+	let mut output = String::new();
+
+	for (index, entry) in &selection.entries {
+		writeln!(output, "* TODO {}", entry.name).unwrap_or_default();
+
+		let properties = selection.fields.iter()
+			.filter(|field| !matches!(field, Field::Name | Field::Description | Field::Tracked))
+			.map(|field| (field.name().to_uppercase(), field.value(*index, entry, now)))
+			.filter(|(_, value)| !value.is_empty())
+			.collect::<Vec<(String, String)>>();
+
+		if !properties.is_empty() {
+			writeln!(output, "  :PROPERTIES:").unwrap_or_default();
+
+			for (name, value) in properties {
+				writeln!(output, "  :{name}: {value}").unwrap_or_default();
+			}
+
+			writeln!(output, "  :END:").unwrap_or_default();
+		}
+
+		if selection.fields.contains(&Field::Tracked) && !entry.time_log.is_empty() {
+			writeln!(output, "  :LOGBOOK:").unwrap_or_default();
+
+			for interval in entry.time_log.iter().rev() {
+				writeln!(output, "  {}", org_clock(interval, state.config.zone())).unwrap_or_default();
+			}
+
+			writeln!(output, "  :END:").unwrap_or_default();
+		}
+
+		if selection.fields.contains(&Field::Description) && !entry.description.is_empty() {
+			writeln!(output, "  {}", entry.description).unwrap_or_default();
+		}
+	}
+
+	output
+}
+
+fn org_clock(interval: &TimeInterval, zone: Zone) -> String {
+	let minutes = interval.duration().num_minutes();
+
+	format!(
+		"CLOCK: [{}]--[{}] => {:>2}:{:02}",
+		zone.localize(interval.start).format("%Y-%m-%d %a %H:%M"),
+		zone.localize(interval.end).format("%Y-%m-%d %a %H:%M"),
+		minutes / 60,
+		minutes % 60
+	)
+}
+
+pub fn html(state: &State, selection: &Selection) -> String {
+	let now = crate::now();
+	let mut rows = String::new();
+
+	let headings = selection.fields.iter()
+		.map(|field| format!("<th>{}</th>", field.heading()))
+		.collect::<String>();
+
+	for (index, entry) in &selection.entries {
+		let cells = selection.fields.iter()
+			.map(|field| format!("<td>{}</td>", html_escape(&field.value(*index, entry, now))))
+			.collect::<String>();
+
+		writeln!(rows, "\t\t\t<tr>{cells}</tr>").unwrap_or_default();
+	}
+
+	let total = selection.entries.iter()
+		.map(|(_, entry)| entry.tracked_time(now))
+		.sum::<chrono::Duration>();
+
+	format!(
+		"<!DOCTYPE html>
+<html lang=\"{language}\">
+<head>
+\t<meta charset=\"utf-8\">
+\t<title>{title}</title>
+\t<style>
+\t\tbody {{ font-family: system-ui, sans-serif; max-width: 50rem; margin: 2rem auto; padding: 0 1rem; }}
+\t\ttable {{ width: 100%; border-collapse: collapse; }}
+\t\tth, td {{ text-align: left; padding: 0.4rem; border-bottom: 1px solid #ddd; vertical-align: top; }}
+\t\tth {{ background: #f4f4f4; }}
+\t\t.summary {{ color: #666; }}
+\t\t@media print {{ th {{ background: none; }} }}
+\t</style>
+</head>
+<body>
+\t<h1>{title}</h1>
+\t<p class=\"summary\">{summary}</p>
+\t<table>
+\t\t<thead>
+\t\t\t<tr>{headings}</tr>
+\t\t</thead>
+\t\t<tbody>
+{rows}\t\t</tbody>
+\t</table>
+</body>
+</html>
+",
+		language = locale::language(),
+		title = html_escape(&tr!("export-html-title")),
+		summary = html_escape(&tr!(
+			"export-html-summary",
+			count = selection.entries.len(),
+			tracked = format_duration(total),
+			date = locale::format_timestamp(now, &state.config)
+		)),
+	)
+}
+
+pub fn csv(selection: &Selection) -> Result<String, String> {
+	let now = crate::now();
+	let mut writer = csv::Writer::from_writer(Vec::new());
+
+	writer.write_record(selection.fields.iter().map(|field| field.name()))
+		.map_err(|error| error.to_string())?;
+
+	for (index, entry) in &selection.entries {
+		writer.write_record(selection.fields.iter().map(|field| field.value(*index, entry, now)))
+			.map_err(|error| error.to_string())?;
+	}
+
+	let data = writer.into_inner().map_err(|error| error.to_string())?;
+
+	String::from_utf8(data).map_err(|error| error.to_string())
+}
+
+pub fn json(selection: &Selection) -> Result<String, String> {
+	let now = crate::now();
+
+	let entries = selection.entries.iter()
+		.map(|(index, entry)| {
+			selection.fields.iter()
+				.map(|field| (field.name().to_string(), serde_json::Value::String(field.value(*index, entry, now))))
+				.collect::<serde_json::Map<String, serde_json::Value>>()
+		})
+		.collect::<Vec<serde_json::Map<String, serde_json::Value>>>();
+
+	serde_json::to_string_pretty(&entries)
+		.map(|data| data + "\n")
+		.map_err(|error| error.to_string())
+}
+
+pub fn html_escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+}
@@ -0,0 +1,77 @@
+use crate::locale::tr;
+use crate::{Color, TodoEntry};
+
+pub struct Filter {
+	conditions: Vec<(bool, Condition)>,
+}
+
+enum Condition {
+	Text(String),
+	Assignee(String),
+	Assigned,
+	Location(String),
+	Located,
+	Color(Color),
+	Estimated,
+	Tracking,
+	Linked,
+}
+
+impl Filter {
+	pub fn parse(expression: &str) -> Result<Self, String> {
+		let conditions = expression.split(" and ")
+			.map(str::trim)
+			.filter(|clause| !clause.is_empty())
+			.map(|clause| match clause.strip_prefix("not ") {
+				Some(clause) => Condition::parse(clause.trim()).map(|condition| (true, condition)),
+				None => Condition::parse(clause).map(|condition| (false, condition)),
+			})
+			.collect::<Result<Vec<(bool, Condition)>, String>>()?;
+
+		Ok(Filter {
+			conditions,
+		})
+	}
+
+	pub fn matches(&self, entry: &TodoEntry) -> bool {
+		self.conditions.iter().all(|(negated, condition)| condition.matches(entry) != *negated)
+	}
+}
+
+impl Condition {
+	fn parse(clause: &str) -> Result<Self, String> {
+		if let Some((key, value)) = clause.split_once('=') {
+			return match key.trim() {
+				"assignee" => Ok(Condition::Assignee(value.to_string())),
+				"location" => Ok(Condition::Location(value.to_string())),
+				"color" => Color::parse(value).map(Condition::Color).ok_or_else(|| {
+					tr!("unknown-color", color = value, colors = Color::ALL.map(Color::name).join(", "))
+				}),
+				key => Err(tr!("filter-unknown-key", key = key)),
+			};
+		}
+
+		Ok(match clause {
+			"assigned" => Condition::Assigned,
+			"located" => Condition::Located,
+			"estimated" => Condition::Estimated,
+			"tracking" => Condition::Tracking,
+			"linked" => Condition::Linked,
+			_ => Condition::Text(clause.to_lowercase()),
+		})
+	}
+
+	fn matches(&self, entry: &TodoEntry) -> bool {
+		match self {
+			Condition::Text(text) => entry.matches(text),
+			Condition::Assignee(assignee) => entry.assigned_to(assignee),
+			Condition::Assigned => entry.assignee.is_some(),
+			Condition::Location(location) => entry.located_at(location),
+			Condition::Located => entry.location.is_some(),
+			Condition::Color(color) => entry.color == Some(*color),
+			Condition::Estimated => entry.estimate_minutes.is_some(),
+			Condition::Tracking => entry.tracking_since.is_some(),
+			Condition::Linked => !entry.links.is_empty(),
+		}
+	}
+}
@@ -0,0 +1,73 @@
+use std::process::{Command, Stdio};
+
+use crate::locale::tr;
+use crate::STATE_FILE_PATH;
+
+pub fn pull() -> bool {
+	if !has_upstream() {
+		tracing::debug!("No upstream branch configured, skipping pull");
+		return true;
+	}
+
+	if let Err(error) = git(&["pull", "--ff-only", "--quiet"]) {
+		tracing::warn!("git pull failed: {error}");
+		eprintln!("{}", tr!("git-pull-failed", path = STATE_FILE_PATH));
+		false
+	} else {
+		tracing::info!("Pulled {STATE_FILE_PATH} with git");
+		true
+	}
+}
+
+pub fn commit() -> bool {
+	if let Err(error) = git(&["add", "--", STATE_FILE_PATH]) {
+		tracing::warn!("git add failed: {error}");
+		eprintln!("{}", tr!("git-stage-failed", path = STATE_FILE_PATH));
+		return false;
+	}
+
+	if git(&["diff", "--cached", "--quiet", "--", STATE_FILE_PATH]).is_ok() {
+		tracing::debug!("{STATE_FILE_PATH} is unchanged, skipping commit");
+		return true;
+	}
+
+	if let Err(error) = git(&["commit", "--quiet", "-m", "Update todo entries", "--", STATE_FILE_PATH]) {
+		tracing::warn!("git commit failed: {error}");
+		eprintln!("{}", tr!("git-commit-failed", path = STATE_FILE_PATH));
+		return false;
+	}
+
+	tracing::info!("Committed {STATE_FILE_PATH} with git");
+
+	if has_upstream() {
+		if let Err(error) = git(&["push", "--quiet"]) {
+			tracing::warn!("git push failed: {error}");
+			eprintln!("{}", tr!("git-push-failed", path = STATE_FILE_PATH));
+			return false;
+		}
+
+		tracing::info!("Pushed {STATE_FILE_PATH} with git");
+	}
+
+	true
+}
+
+fn has_upstream() -> bool {
+	git(&["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{upstream}"]).is_ok()
+}
+
+fn git(arguments: &[&str]) -> Result<(), String> {
+	tracing::debug!("Running git {}", arguments.join(" "));
+
+	let output = Command::new("git")
+		.args(arguments)
+		.stdin(Stdio::null())
+		.output()
+		.map_err(|error| format!("failed to run git: {error}"))?;
+
+	if output.status.success() {
+		Ok(())
+	} else {
+		Err(format!("{} ({})", String::from_utf8_lossy(&output.stderr).trim(), output.status))
+	}
+}
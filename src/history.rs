@@ -0,0 +1,62 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use crate::locale::tr;
+
+pub const HISTORY_FILE_PATH: &str = "history.txt";
+const HISTORY_LIMIT: usize = 1000;
+
+pub fn load() -> Vec<String> {
+	let Ok(data) = std::fs::read_to_string(HISTORY_FILE_PATH) else {
+		return Vec::new();
+	};
+
+	let lines = data.lines().map(ToString::to_string).collect::<Vec<String>>();
+	lines[lines.len().saturating_sub(HISTORY_LIMIT)..].to_vec()
+}
+
+pub fn record(history: &mut Vec<String>, line: &str) {
+	history.push(line.to_string());
+
+	if history.len() > HISTORY_LIMIT {
+		history.remove(0);
+	}
+
+	let written = OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(HISTORY_FILE_PATH)
+		.and_then(|mut file| writeln!(file, "{line}"));
+
+	if let Err(error) = written {
+		tracing::warn!("Failed to write {HISTORY_FILE_PATH}: {error}");
+	}
+}
+
+pub fn expand(history: &[String], line: &str) -> Option<String> {
+	let mut expanded = Vec::new();
+
+	for command in line.split(';').map(str::trim) {
+		let Some(reference) = command.strip_prefix('!') else {
+			expanded.push(command.to_string());
+			continue;
+		};
+
+		let previous = if reference == "!" {
+			history.last()
+		} else {
+			reference.parse::<usize>().ok()
+				.and_then(|number| number.checked_sub(1))
+				.and_then(|number| history.get(number))
+		};
+
+		let Some(previous) = previous else {
+			eprintln!("{}", tr!("history-not-found", reference = command));
+			return None;
+		};
+
+		expanded.push(previous.clone());
+	}
+
+	Some(expanded.join("; "))
+}
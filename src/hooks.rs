@@ -0,0 +1,55 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+
+use crate::locale::tr;
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Hooks {
+	pub on_add: Option<String>,
+	pub on_save: Option<String>,
+	pub on_load: Option<String>,
+}
+
+pub fn run<T: Serialize + ?Sized>(hook: Option<&String>, name: &str, payload: &T) {
+	let Some(hook) = hook else {
+		return;
+	};
+
+	let Ok(data) = serde_json::to_string(payload) else {
+		eprintln!("{}", tr!("hook-serialize-failed", hook = name));
+		return;
+	};
+
+	let mut command = if cfg!(windows) {
+		let mut command = Command::new("cmd");
+		command.arg("/C").arg(hook);
+		command
+	} else {
+		let mut command = Command::new("sh");
+		command.arg("-c").arg(hook);
+		command
+	};
+
+	let child = command
+		.stdin(Stdio::piped())
+		.stdout(Stdio::null())
+		.spawn();
+
+	let Ok(mut child) = child else {
+		eprintln!("{}", tr!("hook-run-failed", hook = name));
+		return;
+	};
+
+	if let Some(mut stdin) = child.stdin.take() {
+		stdin.write_all(data.as_bytes()).unwrap_or_default();
+	}
+
+	match child.wait() {
+		Ok(status) if status.success() => tracing::info!("Ran the {name} hook"),
+		Ok(status) => eprintln!("{}", tr!("hook-exit-status", hook = name, status = status.to_string())),
+		Err(_) => eprintln!("{}", tr!("hook-wait-failed", hook = name)),
+	}
+}
@@ -0,0 +1,543 @@
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use mail_parser::mailbox::maildir::{Flag, MessageIterator};
+use mail_parser::MessageParser;
+use serde::Deserialize;
+
+use crate::locale::tr;
+use crate::timezone::Zone;
+use crate::{parse_duration, Color, EntrySource, State, TimeInterval, TodoEntry};
+
+pub struct Imported {
+	pub entries: Vec<TodoEntry>,
+	pub skipped: usize,
+}
+
+#[derive(Deserialize)]
+struct TaskwarriorTask {
+	pub description: String,
+	#[serde(default)]
+	pub status: String,
+	#[serde(default)]
+	pub annotations: Vec<TaskwarriorAnnotation>,
+}
+
+#[derive(Deserialize)]
+struct TaskwarriorAnnotation {
+	pub description: String,
+}
+
+#[derive(Deserialize)]
+struct CsvRow {
+	#[serde(alias = "title", alias = "Name", alias = "Title")]
+	pub name: String,
+	#[serde(default, alias = "notes", alias = "Description", alias = "Notes")]
+	pub description: String,
+}
+
+#[derive(Deserialize)]
+struct JsonEntry {
+	#[serde(default)]
+	pub name: String,
+	#[serde(default)]
+	pub description: String,
+	#[serde(default)]
+	pub estimate: String,
+	#[serde(default)]
+	pub assignee: String,
+	#[serde(default)]
+	pub location: String,
+	#[serde(default)]
+	pub color: String,
+}
+
+#[derive(Deserialize)]
+struct TrelloBoard {
+	#[serde(default)]
+	pub lists: Vec<TrelloList>,
+	#[serde(default)]
+	pub cards: Vec<TrelloCard>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloList {
+	pub id: String,
+	#[serde(default)]
+	pub closed: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloCard {
+	pub name: String,
+	#[serde(default)]
+	pub desc: String,
+	#[serde(default)]
+	pub id_list: String,
+	#[serde(default)]
+	pub closed: bool,
+}
+
+#[derive(Deserialize)]
+struct GoogleTaskLists {
+	#[serde(default)]
+	pub items: Vec<GoogleTaskList>,
+}
+
+#[derive(Deserialize)]
+struct GoogleTaskList {
+	#[serde(default)]
+	pub items: Vec<GoogleTask>,
+}
+
+#[derive(Deserialize)]
+struct GoogleTask {
+	#[serde(default)]
+	pub title: String,
+	#[serde(default)]
+	pub notes: String,
+	#[serde(default)]
+	pub status: String,
+	#[serde(default)]
+	pub deleted: bool,
+}
+
+pub fn detect(path: &Path, data: &str) -> Option<&'static str> {
+	if path.join("cur").is_dir() && path.join("new").is_dir() {
+		return Some("maildir");
+	}
+
+	let extension = path.extension().map(|extension| extension.to_string_lossy().to_lowercase());
+
+	match extension.as_deref() {
+		Some("ron") => return Some("ron"),
+		Some("org") => return Some("org"),
+		Some("csv") => return Some("csv"),
+		Some("md" | "markdown") => return Some("markdown"),
+		_ => {}
+	}
+
+	let trimmed = data.trim_start();
+
+	if trimmed.starts_with('(') && ron::from_str::<ron::Value>(trimmed).is_ok() {
+		return Some("ron");
+	}
+
+	if let Ok(json) = serde_json::from_str::<serde_json::Value>(trimmed) {
+		return if json.as_array().and_then(|entries| entries.first()).is_some_and(|entry| entry.get("name").is_some()) {
+			Some("json")
+		} else if json.is_array() {
+			Some("taskwarrior")
+		} else if json.get("cards").is_some() {
+			Some("trello")
+		} else if json.get("items").is_some() {
+			Some("google-tasks")
+		} else {
+			None
+		};
+	}
+
+	let lines = data.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<&str>>();
+
+	if lines.iter().any(|line| markdown_task(line).is_some()) {
+		Some("markdown")
+	} else if lines.iter().any(|line| line.starts_with("* TODO ") || line.starts_with("* DONE ")) {
+		Some("org")
+	} else if lines.first().is_some_and(|header| {
+		header.split(',').any(|column| matches!(column.trim().to_lowercase().as_str(), "name" | "title"))
+	}) {
+		Some("csv")
+	} else if extension.as_deref() == Some("txt") || !lines.is_empty() {
+		Some("todo-txt")
+	} else {
+		None
+	}
+}
+
+pub fn ron(data: &str) -> Result<Imported, String> {
+	let state = ron::from_str::<State>(data)
+		.map_err(|error| tr!("import-parse-failed", format = "RON", error = error.to_string()))?;
+
+	Ok(Imported {
+		entries: state.entries,
+		skipped: 0,
+	})
+}
+
+pub fn csv(data: &str) -> Result<Imported, String> {
+	let rows = csv::Reader::from_reader(data.as_bytes())
+		.deserialize::<CsvRow>()
+		.collect::<Result<Vec<CsvRow>, csv::Error>>()
+		.map_err(|error| tr!("import-parse-failed", format = "CSV", error = error.to_string()))?;
+
+	let total = rows.len();
+
+	let entries = rows.into_iter()
+		.filter(|row| !row.name.trim().is_empty())
+		.map(|row| TodoEntry::new(row.name.trim().to_string(), row.description.trim().to_string()))
+		.collect::<Vec<TodoEntry>>();
+
+	Ok(Imported {
+		skipped: total - entries.len(),
+		entries,
+	})
+}
+
+pub fn json(data: &str) -> Result<Imported, String> {
+	let rows = serde_json::from_str::<Vec<JsonEntry>>(data)
+		.map_err(|error| tr!("import-parse-failed", format = "JSON", error = error.to_string()))?;
+
+	let total = rows.len();
+
+	let entries = rows.into_iter()
+		.filter(|row| !row.name.trim().is_empty())
+		.map(|row| {
+			let mut entry = TodoEntry::new(row.name.trim().to_string(), row.description.trim().to_string());
+			let non_empty = |value: String| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+
+			entry.estimate_minutes = row.estimate.split_whitespace()
+				.map(parse_duration)
+				.sum::<Option<chrono::Duration>>()
+				.filter(|estimate| !estimate.is_zero())
+				.map(|estimate| estimate.num_minutes().max(1));
+			entry.assignee = non_empty(row.assignee);
+			entry.location = non_empty(row.location);
+			entry.color = Color::parse(row.color.trim());
+			entry
+		})
+		.collect::<Vec<TodoEntry>>();
+
+	Ok(Imported {
+		skipped: total - entries.len(),
+		entries,
+	})
+}
+
+pub fn todo_txt(data: &str) -> Imported {
+	let mut entries = Vec::<TodoEntry>::new();
+	let mut skipped = 0;
+
+	for line in data.lines().map(str::trim).filter(|line| !line.is_empty()) {
+		if line.starts_with("x ") {
+			skipped += 1;
+			continue;
+		}
+
+		let mut words = line.split_whitespace().peekable();
+
+		if words.peek().is_some_and(|word| word.len() == 3 && word.starts_with('(') && word.ends_with(')')) {
+			words.next();
+		}
+
+		while words.peek().is_some_and(|word| NaiveDate::parse_from_str(word, "%Y-%m-%d").is_ok()) {
+			words.next();
+		}
+
+		let name = words.collect::<Vec<&str>>().join(" ");
+
+		if name.is_empty() {
+			skipped += 1;
+		} else {
+			entries.push(TodoEntry::new(name, String::new()));
+		}
+	}
+
+	Imported { entries, skipped }
+}
+
+pub fn markdown(data: &str) -> Imported {
+	let mut entries = Vec::<TodoEntry>::new();
+	let mut skipped = 0;
+
+	for line in data.lines() {
+		match markdown_task(line.trim_start()) {
+			Some((task, false)) if !task.is_empty() => entries.push(TodoEntry::new(task.to_string(), String::new())),
+			Some(_) => skipped += 1,
+			None => {}
+		}
+	}
+
+	Imported { entries, skipped }
+}
+
+fn markdown_task(line: &str) -> Option<(&str, bool)> {
+	let item = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))?;
+
+	if let Some(task) = item.strip_prefix("[ ] ") {
+		Some((task.trim(), false))
+	} else {
+		item.strip_prefix("[x] ").or_else(|| item.strip_prefix("[X] ")).map(|task| (task.trim(), true))
+	}
+}
+
+pub fn taskwarrior(data: &str) -> Result<Imported, String> {
+	let tasks = serde_json::from_str::<Vec<TaskwarriorTask>>(data).or_else(|_| {
+		data.lines()
+			.filter(|line| !line.trim().is_empty())
+			.map(|line| serde_json::from_str::<TaskwarriorTask>(line.trim().trim_end_matches(',')))
+			.collect::<Result<Vec<TaskwarriorTask>, serde_json::Error>>()
+	}).map_err(|error| tr!("import-parse-failed", format = "Taskwarrior", error = error.to_string()))?;
+
+	let total = tasks.len();
+
+	let entries = tasks.into_iter()
+		.filter(|task| task.status != "completed" && task.status != "deleted" && !task.description.trim().is_empty())
+		.map(|task| {
+			let description = task.annotations.into_iter()
+				.map(|annotation| annotation.description)
+				.collect::<Vec<String>>()
+				.join("; ");
+
+			TodoEntry::new(task.description, description)
+		})
+		.collect::<Vec<TodoEntry>>();
+
+	Ok(Imported {
+		skipped: total - entries.len(),
+		entries,
+	})
+}
+
+pub fn trello(data: &str) -> Result<Imported, String> {
+	let board = serde_json::from_str::<TrelloBoard>(data)
+		.map_err(|error| tr!("import-parse-failed", format = "Trello", error = error.to_string()))?;
+
+	let total = board.cards.len();
+
+	let entries = board.cards.into_iter()
+		.filter(|card| {
+			!card.closed && !board.lists.iter().any(|list| list.id == card.id_list && list.closed)
+		})
+		.map(|card| {
+			let description = card.desc.lines()
+				.map(str::trim)
+				.filter(|line| !line.is_empty())
+				.collect::<Vec<&str>>()
+				.join(" ");
+
+			TodoEntry::new(card.name, description)
+		})
+		.collect::<Vec<TodoEntry>>();
+
+	Ok(Imported {
+		skipped: total - entries.len(),
+		entries,
+	})
+}
+
+pub fn google_tasks(data: &str) -> Result<Imported, String> {
+	let task_lists = serde_json::from_str::<GoogleTaskLists>(data)
+		.map_err(|error| tr!("import-parse-failed", format = "Google Tasks", error = error.to_string()))?;
+
+	let tasks = task_lists.items.into_iter()
+		.flat_map(|task_list| task_list.items)
+		.collect::<Vec<GoogleTask>>();
+
+	let total = tasks.len();
+
+	let entries = tasks.into_iter()
+		.filter(|task| !task.deleted && task.status != "completed" && !task.title.trim().is_empty())
+		.map(|task| {
+			let description = task.notes.lines()
+				.map(str::trim)
+				.filter(|line| !line.is_empty())
+				.collect::<Vec<&str>>()
+				.join(" ");
+
+			TodoEntry::new(task.title, description)
+		})
+		.collect::<Vec<TodoEntry>>();
+
+	Ok(Imported {
+		skipped: total - entries.len(),
+		entries,
+	})
+}
+
+pub fn maildir(root: &Path) -> Result<Imported, String> {
+	let mut messages = MessageIterator::new(root)
+		.map_err(|error| tr!("import-parse-failed", format = "Maildir", error = error.to_string()))?
+		.filter_map(|message| {
+			message.map_err(|error| tracing::warn!("Failed to read a message in {}: {error}", root.display())).ok()
+		})
+		.collect::<Vec<_>>();
+
+	messages.sort_by_key(|message| message.internal_date());
+
+	let total = messages.len();
+	let parser = MessageParser::default();
+
+	let entries = messages.iter()
+		.filter(|message| {
+			let flags = message.flags();
+			!flags.contains(&Flag::Trashed) && (flags.contains(&Flag::Flagged) || !flags.contains(&Flag::Seen))
+		})
+		.filter_map(|message| {
+			let headers = parser.parse_headers(message.contents())?;
+			let from = headers.from()
+				.and_then(|from| from.first())
+				.and_then(|from| from.name().or(from.address()));
+
+			let description = from.into_iter()
+				.map(ToString::to_string)
+				.chain(headers.message_id().map(|id| format!("mid:{id}")))
+				.collect::<Vec<String>>()
+				.join("; ");
+
+			Some(TodoEntry::new(headers.subject().unwrap_or_default().trim().to_string(), description))
+		})
+		.filter(|entry| !entry.name.is_empty())
+		.collect::<Vec<TodoEntry>>();
+
+	Ok(Imported {
+		skipped: total - entries.len(),
+		entries,
+	})
+}
+
+pub fn markdown_vault(root: &Path) -> Result<Vec<TodoEntry>, String> {
+	let mut entries = Vec::<TodoEntry>::new();
+
+	markdown_scan(root, root, &mut entries)?;
+
+	Ok(entries)
+}
+
+fn markdown_scan(root: &Path, directory: &Path, entries: &mut Vec<TodoEntry>) -> Result<(), String> {
+	let mut paths = std::fs::read_dir(directory)
+		.map_err(|_| tr!("directory-read-failed", path = directory.display().to_string()))?
+		.filter_map(|item| Some(item.ok()?.path()))
+		.collect::<Vec<_>>();
+
+	paths.sort();
+
+	for path in paths {
+		let hidden = path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+
+		if hidden {
+			continue;
+		}
+
+		if path.is_dir() {
+			markdown_scan(root, &path, entries)?;
+		} else if path.extension().is_some_and(|extension| extension == "md") {
+			let Ok(data) = std::fs::read_to_string(&path) else {
+				eprintln!("{}", tr!("file-read-failed", path = path.display().to_string()));
+				continue;
+			};
+
+			let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().to_string();
+
+			for (line_index, line) in data.lines().enumerate() {
+				let line = line.trim_start();
+				let task = line.strip_prefix("- [ ] ").or_else(|| line.strip_prefix("* [ ] "));
+
+				if let Some(task) = task.map(str::trim).filter(|task| !task.is_empty()) {
+					let mut entry = TodoEntry::new(task.to_string(), String::new());
+					entry.source = Some(EntrySource {
+						path: relative.clone(),
+						line: line_index + 1,
+					});
+
+					entries.push(entry);
+				}
+			}
+		}
+	}
+
+	Ok(())
+}
+
+pub fn org(data: &str, zone: Zone) -> Imported {
+	let mut entries = Vec::<TodoEntry>::new();
+	let mut skipped = 0;
+	let mut current: Option<TodoEntry> = None;
+	let mut in_drawer = false;
+
+	for line in data.lines() {
+		if line.starts_with('*') {
+			entries.extend(current.take());
+			in_drawer = false;
+
+			let headline = line.trim_start_matches('*').trim();
+
+			if let Some(title) = headline.strip_prefix("TODO ") {
+				current = Some(TodoEntry::new(org_strip_tags(title).to_string(), String::new()));
+			} else if headline.starts_with("DONE ") {
+				skipped += 1;
+			}
+
+			continue;
+		}
+
+		let Some(entry) = current.as_mut() else {
+			continue;
+		};
+
+		let line = line.trim();
+
+		if line.starts_with(':') && line.ends_with(':') && line.len() > 1 {
+			in_drawer = line != ":END:";
+			continue;
+		}
+
+		if let Some(clock) = line.strip_prefix("CLOCK:") {
+			entry.time_log.extend(org_parse_clock(clock, zone));
+			continue;
+		}
+
+		if in_drawer
+			|| line.is_empty()
+			|| line.starts_with("SCHEDULED:")
+			|| line.starts_with("DEADLINE:")
+			|| line.starts_with("CLOSED:") {
+			continue;
+		}
+
+		if !entry.description.is_empty() {
+			entry.description.push(' ');
+		}
+
+		entry.description.push_str(line);
+	}
+
+	entries.extend(current);
+
+	for entry in &mut entries {
+		entry.time_log.sort();
+	}
+
+	Imported {
+		entries,
+		skipped,
+	}
+}
+
+fn org_strip_tags(title: &str) -> &str {
+	let title = title.trim_end();
+
+	match title.rsplit_once(char::is_whitespace) {
+		Some((rest, tags)) if tags.len() > 1 && tags.starts_with(':') && tags.ends_with(':') => rest.trim_end(),
+		_ => title,
+	}
+}
+
+fn org_parse_clock(clock: &str, zone: Zone) -> Option<TimeInterval> {
+	let (range, _) = clock.split_once("=>")?;
+	let (start, end) = range.trim().split_once("--")?;
+
+	Some(TimeInterval {
+		start: org_parse_timestamp(start, zone)?,
+		end: org_parse_timestamp(end, zone)?,
+	})
+}
+
+fn org_parse_timestamp(timestamp: &str, zone: Zone) -> Option<DateTime<Utc>> {
+	let parts = timestamp.trim().trim_matches(|c| c == '[' || c == ']').split_whitespace().collect::<Vec<&str>>();
+	let (date, time) = (parts.first()?, parts.last()?);
+	let local = NaiveDateTime::parse_from_str(&format!("{date} {time}"), "%Y-%m-%d %H:%M").ok()?;
+
+	zone.to_utc(&local)
+}
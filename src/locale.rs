@@ -0,0 +1,159 @@
+use std::sync::OnceLock;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, NaiveDate, Utc};
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::Config;
+
+const DEFAULT_LOCALE: &str = "en";
+const LOCALES: [(&str, &str); 2] = [
+	("en", include_str!("locales/en.ftl")),
+	("de", include_str!("locales/de.ftl")),
+];
+
+static LOCALIZER: OnceLock<Localizer> = OnceLock::new();
+static FALLBACK: OnceLock<Localizer> = OnceLock::new();
+
+struct Localizer {
+	language: String,
+	bundle: FluentBundle<FluentResource>,
+	fallback: FluentBundle<FluentResource>,
+}
+
+macro_rules! tr {
+	($key:literal) => {
+		$crate::locale::text($key, None)
+	};
+	($key:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+		let mut arguments = fluent_bundle::FluentArgs::new();
+		$(arguments.set(stringify!($name), $value);)+
+		$crate::locale::text($key, Some(&arguments))
+	}};
+}
+
+pub(crate) use tr;
+
+pub fn init(configured: Option<&str>) {
+	let requested = configured.map(ToString::to_string).or_else(|| {
+		["LC_ALL", "LC_MESSAGES", "LANG"].iter()
+			.filter_map(|variable| std::env::var(variable).ok())
+			.find(|value| !value.is_empty())
+	});
+
+	let language = requested.as_deref()
+		.and_then(|value| value.split(['_', '.', '-', '@']).next())
+		.map(str::to_lowercase)
+		.filter(|language| LOCALES.iter().any(|(locale, _)| locale == language))
+		.unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+
+	tracing::debug!("Using the {language} locale");
+
+	LOCALIZER.set(Localizer::new(&language)).unwrap_or_default();
+}
+
+pub fn language() -> String {
+	LOCALIZER.get().map_or_else(|| DEFAULT_LOCALE.to_string(), |localizer| localizer.language.clone())
+}
+
+pub fn supported() -> Vec<&'static str> {
+	LOCALES.iter().map(|(locale, _)| *locale).collect()
+}
+
+pub fn format_date(date: NaiveDate) -> String {
+	date.format(&pattern("date-format", "%Y-%m-%d")).to_string()
+}
+
+pub fn format_timestamp(timestamp: DateTime<Utc>, config: &Config) -> String {
+	let local = config.zone().localize(timestamp);
+	let time = if config.twelve_hour_clock {
+		pattern("time-format-12h", "%I:%M %p")
+	} else {
+		pattern("time-format-24h", "%H:%M")
+	};
+
+	format!("{} {}", format_date(local.date_naive()), local.format(&time))
+}
+
+pub fn format_relative(timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+	let elapsed = now - timestamp;
+
+	if elapsed.num_minutes() < 1 {
+		tr!("relative-just-now")
+	} else if elapsed.num_hours() < 1 {
+		tr!("relative-minutes-ago", count = elapsed.num_minutes())
+	} else if elapsed.num_days() < 1 {
+		tr!("relative-hours-ago", count = elapsed.num_hours())
+	} else if elapsed.num_weeks() < 1 {
+		tr!("relative-days-ago", count = elapsed.num_days())
+	} else {
+		tr!("relative-weeks-ago", count = elapsed.num_weeks())
+	}
+}
+
+fn pattern(key: &str, fallback: &str) -> String {
+	let pattern = text(key, None);
+
+	if StrftimeItems::new(&pattern).any(|item| item == Item::Error) {
+		tracing::warn!("Invalid date format {pattern:?} for {key}");
+		return fallback.to_string();
+	}
+
+	pattern
+}
+
+pub fn text(key: &str, arguments: Option<&FluentArgs>) -> String {
+	let localizer = LOCALIZER.get()
+		.unwrap_or_else(|| FALLBACK.get_or_init(|| Localizer::new(DEFAULT_LOCALE)));
+
+	format(&localizer.bundle, key, arguments)
+		.or_else(|| format(&localizer.fallback, key, arguments))
+		.unwrap_or_else(|| {
+			tracing::warn!("Missing translation for {key}");
+			key.to_string()
+		})
+}
+
+fn format(bundle: &FluentBundle<FluentResource>, key: &str, arguments: Option<&FluentArgs>) -> Option<String> {
+	let pattern = bundle.get_message(key)?.value()?;
+	let mut errors = Vec::new();
+	let value = bundle.format_pattern(pattern, arguments, &mut errors);
+
+	for error in errors {
+		tracing::warn!("Failed to format {key}: {error}");
+	}
+
+	Some(value.into_owned())
+}
+
+impl Localizer {
+	fn new(language: &str) -> Self {
+		Localizer {
+			language: language.to_string(),
+			bundle: bundle(language),
+			fallback: bundle(DEFAULT_LOCALE),
+		}
+	}
+}
+
+fn bundle(language: &str) -> FluentBundle<FluentResource> {
+	let identifier = language.parse::<LanguageIdentifier>().unwrap_or_default();
+	let mut bundle = FluentBundle::new_concurrent(vec![identifier]);
+	bundle.set_use_isolating(false);
+
+	let source = LOCALES.iter()
+		.find(|(locale, _)| *locale == language)
+		.map_or(LOCALES[0].1, |(_, source)| source);
+
+	match FluentResource::try_new(source.to_string()) {
+		Ok(resource) => bundle.add_resource_overriding(resource),
+		Err((resource, errors)) => {
+			tracing::warn!("Failed to parse {} messages in the {language} locale", errors.len());
+			bundle.add_resource_overriding(resource);
+		}
+	}
+
+	bundle
+}
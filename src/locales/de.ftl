@@ -0,0 +1,495 @@
+title = Todo Tracker
+date-format = %d.%m.%Y
+time-format-24h = %H:%M
+time-format-12h = %I:%M %p
+enter-command = Befehl eingeben:
+unknown-command = Unbekannter Befehl
+history-not-found = Kein Befehl { $reference } im Verlauf
+chain-stopped = { $count ->
+    [one] Der verbleibende Befehl wurde übersprungen
+   *[other] Die verbleibenden { $count } Befehle wurden übersprungen
+}
+unknown-input = Unbekannte Eingabe
+invalid-index = Kein Eintrag an diesem Index gefunden
+invalid-duration = Ungültige Dauer, erwartet wird ein Wert wie 25m, 90s oder 1h
+invalid-pattern = Ungültiger regulärer Ausdruck: { $error }
+invalid-result = Kein Suchergebnis { $result }, zuerst find ausführen
+missing-log-file-path = Pfad nach --log-file fehlt
+invalid-seed = Fehlende oder ungültige Zahl nach --seed
+read-only-rejected = { $command } ist im Nur-Lese-Modus nicht verfügbar
+read-only-not-writing = { $path } wird im Nur-Lese-Modus nicht geschrieben
+command-already-registered = Ein Befehl mit dem Schlüssel { $command } ist bereits registriert
+config-parse-failed = { $path } konnte nicht gelesen werden, die Standardkonfiguration wird verwendet
+timezone-invalid = Unbekannte Zeitzone { $name }, die Systemzeitzone wird verwendet
+daily-capacity-invalid = Ungültige daily_capacity { $capacity }, erwartet wird ein Wert wie 6h oder 90m
+autosave-interval-invalid = Ungültiges autosave_interval { $interval }, erwartet wird ein Wert wie 30s oder 5m, automatisches Speichern ist aus
+url-titles-unsupported = fetch_url_titles ist gesetzt, aber dieser Build wurde ohne das Feature url-titles kompiliert
+log-file-failed = Protokolldatei { $path } konnte nicht geöffnet werden: { $error }
+file-missing = { $path } konnte nicht gelesen werden. Existiert die Datei?
+file-read-failed = { $path } konnte nicht gelesen werden
+directory-read-failed = Verzeichnis { $path } konnte nicht gelesen werden
+
+help-description = Zeigt eine Hilfe an
+list-description = Listet alle Einträge auf
+add-description = Fügt einen neuen Eintrag hinzu
+remove-description = Entfernt einen Eintrag anhand seines Index (remove all entfernt die letzten Suchergebnisse)
+move-description = Verschiebt einen Eintrag an eine neue Position
+swap-description = Vertauscht die Positionen zweier Einträge
+show-description = Zeigt die Details eines Eintrags
+edit-description = Ändert ein Feld aller passenden Einträge
+rename-description = Ändert den Namen eines Todo-Eintrags
+describe-description = Ändert die Beschreibung eines Todo-Eintrags
+replace-description = Ersetzt einen regulären Ausdruck in Namen und Beschreibungen
+link-description = Verknüpft einen Eintrag mit einem anderen (standardmäßig relates-to)
+estimate-description = Legt fest, wie lange ein Eintrag voraussichtlich dauert (0 entfernt die Schätzung)
+assign-description = Weist einen Eintrag jemandem zu (ein leerer Name hebt die Zuweisung auf)
+locate-description = Legt fest, wo ein Todo-Eintrag erledigt werden kann (ein leerer Ort entfernt ihn)
+color-description = Färbt einen Todo-Eintrag in der Liste ein (eine von { $colors } oder none)
+copy-description = Kopiert Name und Beschreibung eines Todo-Eintrags in die Zwischenablage
+paste-add-description = Fügt einen Todo-Eintrag aus der Zwischenablage hinzu, die erste Zeile wird sein Name
+start-description = Startet die Zeiterfassung für einen Eintrag
+stop-description = Beendet die Zeiterfassung für den aktuellen Eintrag
+timesheet-description = Fasst die erfasste Zeit pro Eintrag und pro Tag zusammen
+pomodoro-description = Startet einen Pomodoro-Timer (standardmäßig 25m) für einen Eintrag
+focus-description = Beschränkt die Liste auf die ersten Einträge (standardmäßig 3)
+unfocus-description = Zeigt wieder alle Einträge in der Liste
+plan-description = Geht die Einträge durch, um die für heute auszuwählen, und fokussiert sie
+pick-description = Wählt zufällig einen Eintrag zum Bearbeiten aus
+count-description = Zählt die Einträge, die dem angegebenen Filter entsprechen
+find-description = Durchsucht die Einträge unscharf, beste Treffer zuerst
+summary-description = Gibt eine einzeilige Zusammenfassung der Einträge aus
+status-description = Gibt eine einzeilige Statusanzeige für Leisten und tmux aus
+serve-description = Stellt die Einträge und eine Weboberfläche über HTTP bereit (standardmäßig Port 8080)
+ctl-description = Sendet list, add oder remove an eine laufende interaktive Sitzung
+mcp-description = Startet einen Model-Context-Protocol-Server über Standardein- und -ausgabe
+import-description = Importiert Einträge aus einer anderen Anwendung (ron, json, taskwarrior, org, trello, google-tasks, maildir, csv, todo-txt, markdown, all)
+export-description = Exportiert die Einträge in ein anderes Format (org, html, csv, json, all)
+vault-description = Importiert offene Markdown-Checkboxen aus einem Notizverzeichnis
+script-description = Führt ein rhai-Skript mit den Einträgen aus
+clear-description = Löscht alle Einträge
+save-description = Speichert die aktuellen Einträge in einer Datei
+load-description = Lädt die Einträge aus einer Datei
+capabilities-description = Listet die Funktionen und Formate dieser Version auf
+version-description = Gibt Version, Build und Zustandsdatei-Details für Fehlerberichte aus
+history-description = Listet frühere Befehle auf, die !<Nummer> erneut ausführt (!! den letzten)
+tutorial-description = Führt durch Hinzufügen, Auflisten, Entfernen und Speichern von Einträgen, ohne die gespeicherten zu verändern
+docs-description = Gibt die Handbuchseite (docs man) oder den vollständigen --help-Text (docs help) aus
+macro-description = Zeichnet Befehlsfolgen auf und spielt sie ab
+schedule-description = Listet die in der Konfiguration geplanten Befehle und ihre nächste Ausführung auf
+exit-description = Beendet das Programm
+
+prompt-name = Name des Eintrags:
+prompt-description = Beschreibung des Eintrags:
+prompt-remove-index = Index des zu entfernenden Eintrags:
+prompt-move-index = Index des zu verschiebenden Eintrags:
+prompt-move-position = Neue Position des Eintrags:
+prompt-swap-first = Index des ersten zu vertauschenden Eintrags:
+prompt-swap-second = Index des zweiten zu vertauschenden Eintrags:
+prompt-show-index = Index des anzuzeigenden Eintrags:
+prompt-rename-index = Index des umzubenennenden Eintrags:
+prompt-describe-index = Index des zu beschreibenden Eintrags:
+prompt-replace-pattern = Zu ersetzender regulärer Ausdruck:
+prompt-replace-replacement = Ersatztext:
+prompt-link-source = Index des zu verknüpfenden Eintrags:
+prompt-link-target = Index des Ziel-Eintrags:
+prompt-estimate-index = Index des zu schätzenden Eintrags:
+prompt-estimate-duration = Erwartete Dauer (z. B. 90m oder 2h):
+prompt-assign-index = Index des zuzuweisenden Eintrags:
+prompt-locate-index = Index des zu verortenden Eintrags:
+prompt-color-index = Index des einzufärbenden Eintrags:
+prompt-copy-index = Index des zu kopierenden Eintrags:
+prompt-assignee = Name der zuständigen Person (leer zum Aufheben):
+prompt-location = Ort (leer zum Entfernen):
+prompt-color = Farbe (leer zum Entfernen):
+prompt-find-query = Zu suchender Text:
+pick-remove = Zu entfernender Eintrag (tippen zum Suchen, Esc zum Abbrechen)
+pick-cancelled = Abgebrochen
+prompt-track-index = Index des zu erfassenden Eintrags:
+prompt-pomodoro-index = Index des zu bearbeitenden Eintrags:
+prompt-import-format = Format für den Import, oder ein Pfad zum Erkennen:
+prompt-import-path = Pfad der zu importierenden Datei:
+prompt-export-format = Format für den Export:
+prompt-vault-path = Pfad des zu durchsuchenden Notizverzeichnisses:
+prompt-script-path = Pfad des auszuführenden Skripts:
+
+nothing-to-list = Keine Einträge vorhanden
+nothing-assigned = { $name } ist nichts zugewiesen
+nothing-at-location = Bei { $location } ist nichts zu erledigen
+list-no-location = Ohne Ort:
+filter-unknown-key = Unbekannter Filterschlüssel { $key }, erwartet wird assignee, location oder color
+smart-list-unknown = Unbekannte gespeicherte Liste { $name } (konfiguriert: { $available })
+nothing-to-pick = Keine Einträge zur Auswahl
+nothing-to-clear = Keine Einträge zum Löschen
+nothing-to-save = Keine Einträge zum Speichern
+nothing-changed = Seit dem letzten Speichern von { $path } hat sich nichts geändert
+nothing-to-replace = Nichts passt zu diesem Muster
+nothing-found = Nichts passt zu { $query }
+no-results = Es gibt keine Suchergebnisse, zuerst find ausführen
+entry-added = Eintrag { $name } hinzugefügt
+entry-removed = Eintrag { $name } entfernt
+entry-removed-at = Eintrag an Index { $index } entfernt
+entry-not-found = Kein Eintrag an Index { $index } gefunden
+duplicate-found = { $index } - { $name } existiert bereits
+duplicate-rejected = Nicht hinzugefügt, { $index } - { $name } existiert bereits
+duplicate-merged = Mit dem vorhandenen Eintrag { $index } - { $name } zusammengeführt
+name-empty = Der Name eines Eintrags darf nicht leer sein
+entry-moved = Eintrag { $name } an Position { $position } verschoben
+entries-swapped = Einträge { $first } und { $second } vertauscht
+entries-linked = { $source } { $relation } { $target }
+already-linked = Diese Einträge sind bereits so verknüpft
+link-to-self = Ein Eintrag kann nicht mit sich selbst verknüpft werden
+estimate-set = { $name } auf { $duration } geschätzt
+estimate-cleared = Schätzung von { $name } entfernt
+entry-assigned = { $name } an { $assignee } zugewiesen
+entry-unassigned = { $name } ist niemandem mehr zugewiesen
+entry-located = { $name } kann bei { $location } erledigt werden
+entry-location-cleared = { $name } hat keinen Ort mehr
+entry-colored = { $name } ist jetzt { $color }
+entry-color-cleared = { $name } ist nicht mehr eingefärbt
+unknown-color = Unbekannte Farbe { $color }, erwartet wird eine von { $colors }
+entry-copied = { $name } in die Zwischenablage kopiert
+url-title-fetched = Eintrag nach dem Seitentitel benannt: { $title }
+url-title-failed = Titel von { $url } konnte nicht abgerufen werden, die URL wird als Name verwendet
+clipboard-failed = Zugriff auf die Zwischenablage fehlgeschlagen
+clipboard-no-text = Die Zwischenablage enthält keinen Text
+remove-linked = { $count ->
+    [one] { $name } wird von einem anderen Eintrag verknüpft:
+   *[other] { $name } wird von { $count } anderen Einträgen verknüpft:
+}
+entries-cleared = { $count ->
+    [one] { $count } Eintrag gelöscht
+   *[other] { $count } Einträge gelöscht
+}
+clear-restore-hint = Eine Sicherung wurde in { $path } geschrieben, mit "import ron { $path }" wird sie wiederhergestellt
+backup-failed = Die Sicherung konnte nicht geschrieben werden, es wurde nichts gelöscht
+position-out-of-range = Position { $position } liegt außerhalb der Liste
+show-entry-description = Beschreibung: { $description }
+show-entry-source = Quelle: { $path }:{ $line }
+show-entry-assignee = Zugewiesen an: { $name }
+show-entry-location = Ort: { $location }
+show-entry-color = Farbe: { $color }
+show-entry-estimate = Schätzung: { $duration }
+show-entry-tracked = Erfasste Zeit: { $duration }
+show-entry-tracking-since = (wird seit { $start } erfasst)
+show-entry-tracking-started = (gestartet { $ago })
+show-entry-links = Verknüpfungen:
+show-entry-backlinks = Verknüpft von:
+show-entry-history = Verlauf:
+show-entry-no-history = Keine Änderungen aufgezeichnet
+show-entry-change = { $timestamp }: { $field ->
+    [name] Name
+    [description] Beschreibung
+    [assignee] Zuweisung
+    [location] Ort
+    [color] Farbe
+    [estimate] Schätzung
+   *[other] { $field }
+} von "{ $old }" zu "{ $new }" geändert
+edit-usage = Verwendung: edit where <Filter> set <Feld>=<Wert>
+edit-filter-empty = Gib einen Filter an oder bearbeite mit edit where all set <Feld>=<Wert> alle Einträge
+edit-unknown-field = Unbekanntes Feld { $field }, erwartet wird name, description, assignee, location, color oder estimate
+entry-edited = { $index }: "{ $old }" -> "{ $new }"
+entries-edited = { $count ->
+    [one] { $count } Eintrag bearbeitet
+   *[other] { $count } Einträge bearbeitet
+}
+picked = Ausgewählt: { $index } - { $name }: { $description }
+summary-open = { $count } offen
+summary-tracking = , erfasse { $name }
+summary-next = , als Nächstes { $name }
+status-usage = Verwendung: status [--format waybar|i3blocks|tmux]
+status-unknown-format = Unbekanntes Statusformat { $format }, erwartet wird waybar, i3blocks oder tmux
+
+focus-enabled = { $count ->
+    [one] Fokus auf den ersten Eintrag, mit move oder swap lässt sich ändern welcher
+   *[other] Fokus auf die ersten { $count } Einträge, mit move oder swap lässt sich ändern welche
+}
+focus-hidden = ({ $count } weitere durch den Fokusmodus ausgeblendet, unfocus zeigt alle an)
+list-estimated = Geschätzt: { $duration }
+over-capacity = Das ist mehr als die tägliche Kapazität von { $capacity }
+focus-invalid-count = Ungültige Anzahl, Fokus auf { $count } Einträge
+focus-needs-entry = Der Fokusmodus benötigt mindestens einen Eintrag
+focus-disabled = Fokusmodus deaktiviert
+focus-not-enabled = Der Fokusmodus ist nicht aktiv
+prompt-plan = (a) annehmen, (s) überspringen, (z) zurückstellen oder (q) beenden:
+nothing-to-plan = Es gibt keine Einträge zum Planen
+plan-empty = Keine Einträge angenommen, Fokusmodus unverändert
+plan-done = { $count ->
+    [one] { $count } Eintrag für heute geplant
+   *[other] { $count } Einträge für heute geplant
+}
+
+tracking-started = Zeiterfassung für { $name } gestartet
+tracking-stopped = Zeiterfassung für { $name } nach { $duration } beendet
+already-tracking = { $name } wird bereits erfasst
+not-tracking = Es wird kein Eintrag erfasst
+timesheet-per-entry = Zeit pro Eintrag:
+timesheet-per-day = Zeit pro Tag:
+timesheet-total = Gesamt: { $duration }
+timesheet-empty = Es wurde noch keine Zeit erfasst
+pomodoro-started = Pomodoro für { $name } gestartet
+pomodoro-remaining = { $time } verbleibend
+pomodoro-finished = Pomodoro für { $name } beendet!
+pomodoro-stats = Abgeschlossene Pomodoros: { $completed } (Serie: { $streak ->
+    [one] { $streak } Tag
+   *[other] { $streak } Tage
+}, Bestwert: { $best })
+
+invalid-port = Ungültiger Port, erwartet wird eine Zahl zwischen 0 und 65535
+missing-host = Adresse nach --host fehlt
+server-started = Einträge werden unter { $url } bereitgestellt
+server-start-failed = Server auf Port { $port } konnte nicht gestartet werden: { $error }
+server-response-failed = Antwort konnte nicht gesendet werden
+server-serialize-failed = Antwort konnte nicht serialisiert werden!
+
+ctl-usage = Verwendung: ctl <list|add|remove> [argumente]
+ctl-add-usage = Verwendung: ctl add <name>
+ctl-no-session = Keine laufende todotracker-Sitzung gefunden
+ctl-own-session = Diese Sitzung besitzt den Steuer-Socket; führe den Befehl direkt aus
+ctl-timeout = Die laufende todotracker-Sitzung hat nicht geantwortet
+ctl-unsupported = Der Steuer-Socket wird nur auf Unix-Plattformen unterstützt
+control-socket-in-use = Eine andere Instanz lauscht bereits auf { $path }
+control-socket-failed = Steuer-Socket { $path } konnte nicht geöffnet werden
+dbus-failed = { $name } konnte nicht am D-Bus-Sitzungsbus registriert werden
+control-connection-failed = Steuerverbindung konnte nicht angenommen werden
+control-added = Eintrag { $name } über den Steuer-Socket hinzugefügt
+control-removed = Eintrag { $name } über den Steuer-Socket entfernt
+
+unknown-import-format = Unbekanntes Importformat { $format }
+import-format-detected = { $path } wird als { $format } importiert
+import-format-undetected = Das Format von { $path } wurde nicht erkannt, verwende import <Format> <Pfad>
+import-parse-failed = { $format }-Export konnte nicht gelesen werden: { $error }
+imported = { $count ->
+    [one] { $count } Eintrag aus { $path } importiert
+   *[other] { $count } Einträge aus { $path } importiert
+}
+import-skipped = { $count ->
+    [one] { $count } erledigter, gelöschter oder archivierter Eintrag übersprungen
+   *[other] { $count } erledigte, gelöschte oder archivierte Einträge übersprungen
+}
+vault-imported = { $count ->
+    [one] { $count } neue Checkbox aus { $path } importiert ({ $known } bereits importiert)
+   *[other] { $count } neue Checkboxen aus { $path } importiert ({ $known } bereits importiert)
+}
+unknown-export-format = Unbekanntes Exportformat { $format }
+unknown-export-field = Unbekanntes Exportfeld "{ $field }", verfügbar sind { $available }
+invalid-manifest-version = Die Manifestversion muss eine Zahl von 1 bis { $latest } sein
+export-failed = Die Einträge konnten nicht als { $format } exportiert werden
+exported = { $count ->
+    [one] { $count } Eintrag nach { $path } exportiert
+   *[other] { $count } Einträge nach { $path } exportiert
+}
+export-html-title = Todo-Einträge
+export-html-summary = { $count } offen, insgesamt { $tracked } erfasst. Exportiert am { $date }.
+export-heading-name = Name
+export-heading-description = Beschreibung
+export-heading-tracked = Erfasst
+export-heading-estimate = Schätzung
+export-heading-assignee = Zugewiesen
+export-heading-location = Ort
+export-heading-color = Farbe
+web-name = Name
+web-description = Beschreibung
+web-add = Hinzufügen
+web-remove = Entfernen
+export-write-failed = Exportierte Einträge konnten nicht nach { $path } geschrieben werden!
+exported-all = { $files } nach { $path } exportiert
+import-all-commands = Die { $path } in dieser Datei führt diese Hooks, Zeitpläne, Webhooks und Git-Synchronisierung aus:
+import-all-commands-skipped = { $count ->
+    [one] Die aktuellen Hooks, Zeitpläne, Webhooks und Git-Synchronisierung wurden statt des einen aus { $path } behalten, --with-hooks importiert ihn
+   *[other] Die aktuellen Hooks, Zeitpläne, Webhooks und Git-Synchronisierung wurden statt der { $count } aus { $path } behalten, --with-hooks importiert sie
+}
+imported-all = { $files } aus { $path } importiert
+import-all-unreadable = { $path } ist kein todotracker-Export
+import-all-write-failed = { $path } konnte nicht geschrieben werden!
+script-usage = Verwendung: script run <datei>
+script-failed = Skript fehlgeschlagen, keine Änderungen übernommen: { $error }
+
+state-saved = Daten in { $path } gespeichert
+state-loaded = { $count ->
+    [one] { $count } Eintrag aus der Datei geladen
+   *[other] { $count } Einträge aus der Datei geladen
+}
+state-missing = An diesem Ort wurde keine Datei gefunden
+state-read-failed-path = { $path } konnte nicht gelesen werden: { $error }
+state-parse-error = { $path } ist ungültig, Zeile { $line }, Spalte { $column }: { $error }
+state-parse-hint-newer = Die Datei stammt von einer neueren Version von todotracker (Manifestversion { $saved }, diese Version liest bis { $current }), aktualisiere todotracker, um sie zu laden
+state-parse-hint-missing-field = Ergänze das fehlende Feld `{ $field }` von Hand oder stelle eine state.backup-*.ron-Datei mit "import ron <Pfad>" wieder her
+state-parse-hint-unknown-value = `{ $value }` ist kein Wert, den diese Version kennt, korrigiere ihn oder aktualisiere todotracker
+state-serialize-failed = Daten konnten nicht gespeichert werden!
+state-write-failed = Daten konnten nicht in die Datei geschrieben werden!
+config-serialize-failed = Konfiguration konnte nicht serialisiert werden!
+config-write-failed = Konfiguration konnte nicht nach { $path } geschrieben werden!
+state-changed-on-disk = { $path } wurde außerhalb dieser Sitzung geändert, load lädt die Datei neu, save überschreibt die Änderung
+confirm-overwrite-changed = { $path } wurde außerhalb dieser Sitzung geändert, diese Änderung überschreiben? (y/n)
+manifest-older = Diese Datei hat eine ältere Manifestversion und wird eventuell nicht korrekt geladen
+manifest-newer = Diese Datei wurde mit einer neueren Version erstellt und wird eventuell nicht korrekt geladen
+manifest-newer-not-writing = { $path } wird nicht geschrieben, da die Datei mit einer neueren Version erstellt wurde und Daten verloren gingen
+confirm-override = Aktuelle Einträge überschreiben? (y/n)
+confirm-import-all-commands = Diese Befehle aus der importierten Konfiguration ausführen? (y/n)
+confirm-import-all = Dies ersetzt { $files } und die aktuellen Einträge, fortfahren? (y/n)
+confirm-import = { $count ->
+    [one] Diesen Eintrag importieren? (y/n)
+   *[other] Diese { $count } Einträge importieren? (y/n)
+}
+confirm-add-duplicate = Trotzdem hinzufügen? (y/n)
+confirm-save-empty = Es gibt keine Einträge, trotzdem speichern und { $path } leeren? (y/n)
+confirm-exit = Es gibt eine gespeicherte Datei, aber ungespeicherte Änderungen. Wirklich beenden? (y/n)
+confirm-remove-linked = Trotzdem mitsamt Verknüpfungen entfernen? (y/n)
+confirm-remove-results = { $count ->
+    [one] Diesen Eintrag entfernen? (y/n)
+   *[other] Diese { $count } Einträge entfernen? (y/n)
+}
+confirm-replace = { $count ->
+    [one] Diese Änderung übernehmen? (y/n)
+   *[other] Diese Änderungen an { $count } Einträgen übernehmen? (y/n)
+}
+confirm-clear = { $count ->
+    [one] Den einzigen Eintrag löschen? (y/n)
+   *[other] Alle { $count } Einträge löschen? (y/n)
+}
+
+emergency-saved = Ungespeicherte Einträge wurden in { $path } geschrieben und beim nächsten Start zur Wiederherstellung angeboten
+recovery-found = { $count ->
+    [one] Ein Eintrag in { $path } wurde beim Ende der letzten Sitzung nicht gespeichert
+   *[other] { $count } Einträge in { $path } wurden beim Ende der letzten Sitzung nicht gespeichert
+}
+confirm-recover = Die ungespeicherten Einträge laden? Die Datei wird in jedem Fall entfernt (y/n)
+recovery-loaded = { $count ->
+    [one] Ein Eintrag wiederhergestellt, mit save wird er gespeichert
+   *[other] { $count } Einträge wiederhergestellt, mit save werden sie gespeichert
+}
+recovery-unreadable = Ungespeicherte Einträge aus { $path } konnten nicht gelesen werden, die Datei bleibt erhalten
+
+dry-run-header = Probelauf, diese Änderungen werden nicht gespeichert:
+dry-run-reordered = Einträge neu angeordnet
+dry-run-focus-changed = Fokusmodus geändert
+dry-run-pomodoros-updated = Pomodoro-Protokoll aktualisiert
+dry-run-would-write = { $count ->
+    [one] Würde { $count } Eintrag nach { $path } schreiben
+   *[other] Würde { $count } Einträge nach { $path } schreiben
+}
+dry-run-would-export = { $count ->
+    [one] Würde { $count } Eintrag nach { $path } exportieren
+   *[other] Würde { $count } Einträge nach { $path } exportieren
+}
+dry-run-would-write-config = Würde die Konfiguration nach { $path } schreiben
+dry-run-would-import-all = Würde { $files } ersetzen
+
+git-pull-failed = { $path } konnte nicht mit git abgerufen werden. Wenn beide Seiten geändert wurden, muss der Verlauf von Hand zusammengeführt werden
+git-stage-failed = { $path } konnte nicht mit git vorgemerkt werden. Ist dieses Verzeichnis ein git-Repository?
+git-commit-failed = { $path } konnte nicht mit git committet werden
+git-push-failed = { $path } konnte nicht mit git übertragen werden, dies wird beim nächsten Speichern nachgeholt
+hook-serialize-failed = Daten für den Hook { $hook } konnten nicht serialisiert werden!
+hook-run-failed = Der Hook { $hook } konnte nicht ausgeführt werden
+hook-exit-status = Der Hook { $hook } wurde mit { $status } beendet
+hook-wait-failed = Auf den Hook { $hook } konnte nicht gewartet werden
+webhook-failed = Webhook { $event } konnte nicht zugestellt werden: { $error }
+
+capabilities-version = Version: { $version }
+capabilities-features = Funktionen: { $features }
+capabilities-none = keine
+capabilities-formats = Formate: { $formats }
+capabilities-backends = Speicher: { $backends }
+capabilities-manifest-versions = Manifestversionen: { $versions }
+capabilities-locales = Sprachen: { $locales }
+capabilities-commands = Befehle: { $commands }
+capabilities-serialize-failed = Fähigkeiten konnten nicht serialisiert werden!
+
+version-commit = Commit: { $commit }
+version-built = Erstellt: { $date }
+version-unknown = unbekannt
+version-manifest = Manifest-Version: { $version } ({ $path } hat { $saved })
+version-manifest-unreadable = Manifest-Version: { $version } ({ $path } konnte nicht gelesen werden)
+version-manifest-missing = Manifest-Version: { $version } (keine { $path } hier)
+
+relative-just-now = gerade eben
+relative-minutes-ago = { $count ->
+    [one] vor einer Minute
+   *[other] vor { $count } Minuten
+}
+relative-hours-ago = { $count ->
+    [one] vor einer Stunde
+   *[other] vor { $count } Stunden
+}
+relative-days-ago = { $count ->
+    [one] gestern
+   *[other] vor { $count } Tagen
+}
+relative-weeks-ago = { $count ->
+    [one] letzte Woche
+   *[other] vor { $count } Wochen
+}
+
+no-macros = Noch keine Makros aufgezeichnet, starte eines mit macro record <Name>
+macro-usage = Verwendung: macro record <Name>, macro stop, macro run <Name> oder macro list
+macro-recording = Makro { $name } wird aufgezeichnet, beende es mit macro stop
+macro-already-recording = Makro { $name } wird bereits aufgezeichnet
+macro-not-recording = Es wird kein Makro aufgezeichnet
+macro-empty = Makro { $name } enthält keine Befehle und wurde nicht gespeichert
+macro-saved = { $count ->
+    [one] Makro { $name } mit einem Befehl gespeichert
+   *[other] Makro { $name } mit { $count } Befehlen gespeichert
+}
+macro-not-found = Kein Makro namens { $name }
+
+setup-welcome = Keine { $path } und keine gespeicherten Einträge gefunden, daher werden jetzt einige Einstellungen festgelegt. Eine leere Antwort behält die Voreinstellung.
+setup-locale = Sprache ({ $locales }), leer für die des Systems:
+setup-timezone = Zeitzone wie Europe/Berlin, leer für die des Systems:
+setup-twelve-hour-clock = Zeiten im 12-Stunden-Format anzeigen? (y/n)
+setup-daily-capacity = Tageskapazität wie 6h, leer für keine:
+setup-git-sync = Die Zustandsdatei bei jedem Speichern im umgebenden Git-Repository committen? (y/n)
+setup-finished = Einstellungen in { $path } gespeichert, die jederzeit bearbeitet werden kann
+setup-locale-restart = Die Sprache wird ab dem nächsten Start verwendet
+
+tutorial-welcome = Dieses Tutorial verwendet eine leere Übungsliste, deine Einträge und Dateien bleiben also unverändert. Mit exit lässt es sich jederzeit verlassen.
+tutorial-add = Schritt 1 von 4: Füge einen Eintrag hinzu, zum Beispiel: add Brot kaufen
+tutorial-list = Schritt 2 von 4: Zeige deine Einträge mit: list
+tutorial-remove = Schritt 3 von 4: Ist ein Eintrag erledigt, entferne ihn über seine Nummer, zum Beispiel: remove 0
+tutorial-save = Schritt 4 von 4: Speichere deine Einträge mit: save
+tutorial-step-done = Gut gemacht!
+tutorial-try-again = Damit ist dieser Schritt noch nicht erledigt, versuche es noch einmal
+tutorial-stopped = Tutorial verlassen, deine Einträge sind unverändert
+tutorial-finished = Geschafft! Mit help werden alle weiteren Befehle angezeigt
+
+docs-usage = Verwendung: todotracker [OPTIONEN] [BEFEHL [ARGUMENTE]]
+docs-options = Optionen:
+docs-commands = Befehle (ohne Befehl wird eine interaktive Sitzung gestartet):
+docs-files = Einträge werden in { $state } gespeichert und Einstellungen aus { $config } gelesen, beide im aktuellen Verzeichnis.
+docs-summary = Todo-Einträge auf der Kommandozeile verwalten
+docs-about = Ohne Befehl startet todotracker eine interaktive Sitzung, die einen Befehl pro Zeile liest; mehrere Befehle können mit ; getrennt werden. Mit einem Befehl wird dieser auf die Zustandsdatei im aktuellen Verzeichnis angewendet und die Datei erneut gespeichert, falls sich etwas geändert hat.
+docs-state-file = Die Todo-Einträge, Zeiterfassung und der Fokusmodus des aktuellen Verzeichnisses.
+docs-config-file = Einstellungen wie Sprache, Zeitzone, Hooks, Smart Lists und Makros.
+docs-usage-command = Verwendung: docs man oder docs help
+option-help = Gibt diesen Hilfetext aus
+option-quiet = Schaltet die Protokollierung und, bei einem einzelnen Befehl, dessen Ausgabe ab
+option-verbose = Protokolliert Befehle, Speichervorgänge, Hooks und Git-Synchronisation, mit jedem weiteren v ausführlicher
+option-log-file = Hängt das Protokoll an eine Datei an statt an die Standardfehlerausgabe
+option-dry-run = Zeigt, was Befehle ändern würden, ohne etwas zu speichern
+option-read-only = Erlaubt nur Befehle, die die Einträge nicht verändern
+option-sandbox = Startet mit Beispieleinträgen und liest oder schreibt keine Dateien
+option-deterministic = Verwendet eine feste Uhrzeit, UTC und geseedete Zufallsauswahl, damit Ausgaben zwischen Läufen vergleichbar sind
+option-seed = Seed für --deterministic, das damit aktiviert wird (standardmäßig 0)
+
+no-schedules = Keine Befehle geplant, sie werden unter schedules in config.ron eingetragen
+schedule-usage = Verwendung: schedule list
+schedule-next = nächste Ausführung { $time }
+schedule-never = wird nie ausgeführt
+schedule-running = Führe geplanten Befehl aus: { $command }
+prompt-skipped = Abfrage "{ $prompt }" übersprungen, da geplante Befehle keine Eingaben abfragen können
+input-required = { $command } liest vom Terminal und kann nicht als geplanter Befehl laufen
+cron-field-count = Ungültiger Zeitplan { $expression }, erwartet werden Minute, Stunde, Tag des Monats, Monat und Wochentag
+cron-invalid-field = Ungültiges Zeitplanfeld { $field }
+
+sandbox-started = Sandbox-Modus: dies sind Beispieleinträge und nichts wird gespeichert
+sandbox-not-writing = Sandbox-Modus, { $path } wird nicht geschrieben
+sandbox-report = Quartalsbericht schreiben
+sandbox-report-description = Die Zahlen liegen im gemeinsamen Laufwerk
+sandbox-groceries = Einkaufen
+sandbox-groceries-description = Milch, Brot und Eier
+sandbox-supermarket = Supermarkt
+sandbox-dentist = Zahnarzt anrufen
+sandbox-bike = Fahrradbremsen reparieren
+sandbox-garage = Garage
+sandbox-trip = Wochenendausflug planen
+sandbox-trip-description = Snacks vom Einkauf einpacken
@@ -0,0 +1,495 @@
+title = Todo Tracker
+date-format = %Y-%m-%d
+time-format-24h = %H:%M
+time-format-12h = %I:%M %p
+enter-command = Enter a command:
+unknown-command = Unknown command
+history-not-found = No command { $reference } in the history
+chain-stopped = { $count ->
+    [one] Skipped the remaining command
+   *[other] Skipped the remaining { $count } commands
+}
+unknown-input = Unknown input
+invalid-index = No entry found at that index
+invalid-duration = Invalid duration, expected a value like 25m, 90s or 1h
+invalid-pattern = Invalid regular expression: { $error }
+invalid-result = No search result { $result }, run find first
+missing-log-file-path = Missing path after --log-file
+invalid-seed = Missing or invalid number after --seed
+read-only-rejected = { $command } is not available in read-only mode
+read-only-not-writing = Not writing { $path } in read-only mode
+command-already-registered = A command with the key { $command } is already registered
+config-parse-failed = Failed to parse { $path }, using the default configuration
+timezone-invalid = Unknown timezone { $name }, using the system timezone
+daily-capacity-invalid = Invalid daily_capacity { $capacity }, expected a value like 6h or 90m
+autosave-interval-invalid = Invalid autosave_interval { $interval }, expected a value like 30s or 5m, autosaving is off
+url-titles-unsupported = fetch_url_titles is set, but this build was compiled without the url-titles feature
+log-file-failed = Failed to open log file { $path }: { $error }
+file-missing = Failed to read { $path }. Are you sure it exists?
+file-read-failed = Failed to read { $path }
+directory-read-failed = Failed to read directory { $path }
+
+help-description = Displays a help message
+list-description = Lists all todo entries
+add-description = Adds a new todo entry
+remove-description = Removes a todo entry by its index (remove all removes the last search results)
+move-description = Moves a todo entry to a new position
+swap-description = Swaps the positions of two todo entries
+show-description = Shows the details of a todo entry
+edit-description = Changes a field of every matching todo entry
+rename-description = Changes the name of a todo entry
+describe-description = Changes the description of a todo entry
+replace-description = Replaces a regex in entry names and descriptions
+link-description = Links one todo entry to another (relates-to by default)
+estimate-description = Sets how long a todo entry is expected to take (0 clears it)
+assign-description = Assigns a todo entry to someone (an empty name unassigns it)
+locate-description = Sets where a todo entry can be done (an empty place clears it)
+color-description = Colors a todo entry in the list (one of { $colors } or none)
+copy-description = Copies the name and description of a todo entry to the clipboard
+paste-add-description = Adds a todo entry from the clipboard, the first line becoming its name
+start-description = Starts tracking time spent on a todo entry
+stop-description = Stops tracking time on the current todo entry
+timesheet-description = Summarizes tracked time per entry and per day
+pomodoro-description = Runs a pomodoro timer (25m by default) for a todo entry
+focus-description = Limits the list to the first few todo entries (3 by default)
+unfocus-description = Shows all todo entries in the list again
+plan-description = Steps through the todo entries to pick the ones for today and focuses on them
+pick-description = Picks a random todo entry to work on
+count-description = Counts the todo entries matching the given filter
+find-description = Fuzzy searches the todo entries, best matches first
+summary-description = Prints a one-line summary of the todo entries
+status-description = Prints a one-line status for bars and tmux
+serve-description = Serves the todo entries and a web UI over HTTP (port 8080 by default)
+ctl-description = Sends list, add or remove to a running interactive session
+mcp-description = Runs a Model Context Protocol server over standard input and output
+import-description = Imports todo entries from another application (ron, json, taskwarrior, org, trello, google-tasks, maildir, csv, todo-txt, markdown, all)
+export-description = Exports the todo entries to another format (org, html, csv, json, all)
+vault-description = Imports unchecked Markdown checkboxes from a notes directory
+script-description = Runs a rhai script against the todo entries
+clear-description = Clears all todo entries
+save-description = Saves the current todo entries to a file
+load-description = Loads the todo entries from a file
+capabilities-description = Lists the features and formats supported by this build
+version-description = Prints the version, build and state file details to include in bug reports
+history-description = Lists previous commands, which !<number> runs again (!! runs the last one)
+tutorial-description = Walks through adding, listing, removing and saving entries without touching the saved ones
+docs-description = Prints the manual page (docs man) or the full --help text (docs help)
+macro-description = Records and replays command sequences
+schedule-description = Lists the commands scheduled in the configuration and when they run next
+exit-description = Exits the program
+
+prompt-name = Name of todo entry:
+prompt-description = Description of todo entry:
+prompt-remove-index = Index of entry to remove:
+prompt-move-index = Index of entry to move:
+prompt-move-position = New position of entry:
+prompt-swap-first = Index of first entry to swap:
+prompt-swap-second = Index of second entry to swap:
+prompt-show-index = Index of entry to show:
+prompt-rename-index = Index of entry to rename:
+prompt-describe-index = Index of entry to describe:
+prompt-replace-pattern = Regular expression to replace:
+prompt-replace-replacement = Replacement text:
+prompt-link-source = Index of entry to link from:
+prompt-link-target = Index of entry to link to:
+prompt-estimate-index = Index of entry to estimate:
+prompt-estimate-duration = Expected duration (e.g. 90m or 2h):
+prompt-assign-index = Index of entry to assign:
+prompt-locate-index = Index of entry to locate:
+prompt-color-index = Index of entry to color:
+prompt-copy-index = Index of entry to copy:
+prompt-assignee = Name of assignee (empty to unassign):
+prompt-location = Location (empty to clear):
+prompt-color = Color (empty to clear):
+prompt-find-query = Text to search for:
+pick-remove = Entry to remove (type to search, Esc to cancel)
+pick-cancelled = Cancelled
+prompt-track-index = Index of entry to track:
+prompt-pomodoro-index = Index of entry to work on:
+prompt-import-format = Format to import from, or a path to detect it from:
+prompt-import-path = Path of file to import:
+prompt-export-format = Format to export to:
+prompt-vault-path = Path of notes directory to scan:
+prompt-script-path = Path of script to run:
+
+nothing-to-list = Nothing to list
+nothing-assigned = Nothing is assigned to { $name }
+nothing-at-location = Nothing is located at { $location }
+list-no-location = No location:
+filter-unknown-key = Unknown filter key { $key }, expected assignee, location or color
+smart-list-unknown = Unknown smart list { $name } (configured: { $available })
+nothing-to-pick = Nothing to pick from
+nothing-to-clear = Nothing to clear
+nothing-to-save = Nothing to save
+nothing-changed = Nothing changed since { $path } was last saved
+nothing-to-replace = Nothing matches that pattern
+nothing-found = Nothing matches { $query }
+no-results = There are no search results, run find first
+entry-added = Added entry { $name }
+entry-removed = Removed entry { $name }
+entry-removed-at = Removed entry at index { $index }
+entry-not-found = No todo entry found at index { $index }
+duplicate-found = { $index } - { $name } already exists
+duplicate-rejected = Not added, { $index } - { $name } already exists
+duplicate-merged = Merged into the existing entry { $index } - { $name }
+name-empty = The name of an entry cannot be empty
+entry-moved = Moved entry { $name } to position { $position }
+entries-swapped = Swapped entries { $first } and { $second }
+entries-linked = { $source } { $relation } { $target }
+already-linked = These entries are already linked that way
+link-to-self = An entry cannot be linked to itself
+estimate-set = Estimated { $name } at { $duration }
+estimate-cleared = Cleared the estimate of { $name }
+entry-assigned = Assigned { $name } to { $assignee }
+entry-unassigned = { $name } is no longer assigned to anyone
+entry-located = { $name } can be done at { $location }
+entry-location-cleared = { $name } no longer has a location
+entry-colored = Colored { $name } { $color }
+entry-color-cleared = { $name } is no longer colored
+unknown-color = Unknown color { $color }, expected one of { $colors }
+entry-copied = Copied { $name } to the clipboard
+url-title-fetched = Named the entry after the page title: { $title }
+url-title-failed = Failed to fetch the title of { $url }, the URL is used as the name
+clipboard-failed = Failed to access the clipboard
+clipboard-no-text = The clipboard does not contain any text
+remove-linked = { $count ->
+    [one] { $name } is linked from another entry:
+   *[other] { $name } is linked from { $count } other entries:
+}
+entries-cleared = { $count ->
+    [one] { $count } entry cleared
+   *[other] { $count } entries cleared
+}
+clear-restore-hint = A backup was written to { $path }, run "import ron { $path }" to restore it
+backup-failed = Failed to write a backup, nothing was cleared
+position-out-of-range = Position { $position } is out of range
+show-entry-description = Description: { $description }
+show-entry-source = Source: { $path }:{ $line }
+show-entry-assignee = Assigned to: { $name }
+show-entry-location = Location: { $location }
+show-entry-color = Color: { $color }
+show-entry-estimate = Estimate: { $duration }
+show-entry-tracked = Tracked time: { $duration }
+show-entry-tracking-since = (tracking since { $start })
+show-entry-tracking-started = (started { $ago })
+show-entry-links = Links:
+show-entry-backlinks = Linked from:
+show-entry-history = History:
+show-entry-no-history = No changes recorded
+show-entry-change = { $timestamp }: { $field ->
+    [name] name
+    [description] description
+    [assignee] assignee
+    [location] location
+    [color] color
+    [estimate] estimate
+   *[other] { $field }
+} changed from "{ $old }" to "{ $new }"
+edit-usage = Usage: edit where <filter> set <field>=<value>
+edit-filter-empty = Give a filter, or use edit where all set <field>=<value> to edit every entry
+edit-unknown-field = Unknown field { $field }, expected name, description, assignee, location, color or estimate
+entry-edited = { $index }: "{ $old }" -> "{ $new }"
+entries-edited = { $count ->
+    [one] { $count } entry edited
+   *[other] { $count } entries edited
+}
+picked = Picked { $index } - { $name }: { $description }
+summary-open = { $count } open
+summary-tracking = , tracking { $name }
+summary-next = , next { $name }
+status-usage = Usage: status [--format waybar|i3blocks|tmux]
+status-unknown-format = Unknown status format { $format }, expected waybar, i3blocks or tmux
+
+focus-enabled = { $count ->
+    [one] Focusing on the first entry, use move or swap to change which
+   *[other] Focusing on the first { $count } entries, use move or swap to change which
+}
+focus-hidden = ({ $count } more hidden by focus mode, use unfocus to show all)
+list-estimated = Estimated: { $duration }
+over-capacity = This is more than the daily capacity of { $capacity }
+focus-invalid-count = Invalid entry count, focusing on { $count } entries
+focus-needs-entry = Focus mode needs at least one entry
+focus-disabled = Focus mode disabled
+focus-not-enabled = Focus mode is not enabled
+prompt-plan = (a)ccept, (s)kip, snoo(z)e or (q)uit:
+nothing-to-plan = There are no todo entries to plan
+plan-empty = No todo entries accepted, focus mode unchanged
+plan-done = { $count ->
+    [one] Planned { $count } entry for today
+   *[other] Planned { $count } entries for today
+}
+
+tracking-started = Started tracking { $name }
+tracking-stopped = Stopped tracking { $name } after { $duration }
+already-tracking = Already tracking { $name }
+not-tracking = No entry is being tracked
+timesheet-per-entry = Time per entry:
+timesheet-per-day = Time per day:
+timesheet-total = Total: { $duration }
+timesheet-empty = No time has been tracked
+pomodoro-started = Pomodoro started for { $name }
+pomodoro-remaining = { $time } remaining
+pomodoro-finished = Pomodoro finished for { $name }!
+pomodoro-stats = Completed pomodoros: { $completed } (streak: { $streak ->
+    [one] { $streak } day
+   *[other] { $streak } days
+}, best: { $best })
+
+invalid-port = Invalid port, expected a number between 0 and 65535
+missing-host = Missing address after --host
+server-started = Serving todo entries on { $url }
+server-start-failed = Failed to start server on port { $port }: { $error }
+server-response-failed = Failed to send response
+server-serialize-failed = Failed to serialize response!
+
+ctl-usage = Usage: ctl <list|add|remove> [arguments]
+ctl-add-usage = Usage: ctl add <name>
+ctl-no-session = No running todotracker session found
+ctl-own-session = This session owns the control socket; run the command directly instead
+ctl-timeout = The running todotracker session did not respond
+ctl-unsupported = The control socket is only supported on Unix platforms
+control-socket-in-use = Another instance is already listening on { $path }
+control-socket-failed = Failed to open control socket at { $path }
+dbus-failed = Failed to register { $name } on the D-Bus session bus
+control-connection-failed = Failed to accept control connection
+control-added = Added entry { $name } from the control socket
+control-removed = Removed entry { $name } from the control socket
+
+unknown-import-format = Unknown import format { $format }
+import-format-detected = Importing { $path } as { $format }
+import-format-undetected = Could not tell the format of { $path }, use import <format> <path>
+import-parse-failed = Failed to parse { $format } export: { $error }
+imported = { $count ->
+    [one] Imported { $count } entry from { $path }
+   *[other] Imported { $count } entries from { $path }
+}
+import-skipped = { $count ->
+    [one] Skipped { $count } completed, deleted or archived item
+   *[other] Skipped { $count } completed, deleted or archived items
+}
+vault-imported = { $count ->
+    [one] Imported { $count } new checkbox from { $path } ({ $known } already imported)
+   *[other] Imported { $count } new checkboxes from { $path } ({ $known } already imported)
+}
+unknown-export-format = Unknown export format { $format }
+unknown-export-field = Unknown export field "{ $field }", available are { $available }
+invalid-manifest-version = The manifest version must be a number from 1 to { $latest }
+export-failed = Failed to export the entries as { $format }
+exported = { $count ->
+    [one] Exported { $count } entry to { $path }
+   *[other] Exported { $count } entries to { $path }
+}
+export-html-title = Todo entries
+export-html-summary = { $count } open, { $tracked } tracked in total. Exported { $date }.
+export-heading-name = Name
+export-heading-description = Description
+export-heading-tracked = Tracked
+export-heading-estimate = Estimate
+export-heading-assignee = Assignee
+export-heading-location = Location
+export-heading-color = Color
+web-name = Name
+web-description = Description
+web-add = Add
+web-remove = Remove
+export-write-failed = Failed to write exported entries to { $path }!
+exported-all = Exported { $files } to { $path }
+import-all-commands = The { $path } in this file runs these hooks, schedules, webhooks and git sync:
+import-all-commands-skipped = { $count ->
+    [one] Kept the current hooks, schedules, webhooks and git sync instead of the one in { $path }, add --with-hooks to import it
+   *[other] Kept the current hooks, schedules, webhooks and git sync instead of the { $count } in { $path }, add --with-hooks to import them
+}
+imported-all = Imported { $files } from { $path }
+import-all-unreadable = { $path } is not a todotracker export
+import-all-write-failed = Failed to write { $path }!
+script-usage = Usage: script run <file>
+script-failed = Script failed, no changes were kept: { $error }
+
+state-saved = Saved state data to { $path }
+state-loaded = { $count ->
+    [one] Loaded { $count } entry from state file
+   *[other] Loaded { $count } entries from state file
+}
+state-missing = No state data file found at that location
+state-read-failed-path = Failed to read { $path }: { $error }
+state-parse-error = { $path } is not valid, line { $line }, column { $column }: { $error }
+state-parse-hint-newer = It was written by a newer version of todotracker (manifest version { $saved }, this build reads up to { $current }), update todotracker to load it
+state-parse-hint-missing-field = Add the missing `{ $field }` field by hand, or restore a state.backup-*.ron file with "import ron <path>"
+state-parse-hint-unknown-value = `{ $value }` is not a value this version knows, fix it or update todotracker
+state-serialize-failed = Failed to save state to a file!
+state-write-failed = Failed to write state data to file!
+config-serialize-failed = Failed to serialize the configuration!
+config-write-failed = Failed to write the configuration to { $path }!
+state-changed-on-disk = { $path } was changed outside this session, use load to reload it or save to overwrite the change
+confirm-overwrite-changed = { $path } was changed outside this session, overwrite that change? (y/n)
+manifest-older = This save file has an old manifest version, and may not load correctly
+manifest-newer = This save file has been created with a newer version, and may not load correctly
+manifest-newer-not-writing = Not writing { $path }, because it was created with a newer version and would lose data
+confirm-override = Override current entries? (y/n)
+confirm-import-all-commands = Run these commands from the imported configuration? (y/n)
+confirm-import-all = This replaces { $files } and the current entries, continue? (y/n)
+confirm-import = { $count ->
+    [one] Import this entry? (y/n)
+   *[other] Import these { $count } entries? (y/n)
+}
+confirm-add-duplicate = Add it anyway? (y/n)
+confirm-save-empty = There are no entries, save anyway and empty { $path }? (y/n)
+confirm-exit = A save file exists, but you have unsaved data. Are you sure you want to quit? (y/n)
+confirm-remove-linked = Remove it and its links anyway? (y/n)
+confirm-remove-results = { $count ->
+    [one] Remove this entry? (y/n)
+   *[other] Remove these { $count } entries? (y/n)
+}
+confirm-replace = { $count ->
+    [one] Apply this change? (y/n)
+   *[other] Apply these changes to { $count } entries? (y/n)
+}
+confirm-clear = { $count ->
+    [one] Clear the only entry? (y/n)
+   *[other] Clear all { $count } entries? (y/n)
+}
+
+emergency-saved = Unsaved entries were written to { $path } and will be offered for recovery on the next start
+recovery-found = { $count ->
+    [one] Found one entry in { $path } that was not saved when the last session ended
+   *[other] Found { $count } entries in { $path } that were not saved when the last session ended
+}
+confirm-recover = Load the unsaved entries? The file is removed either way (y/n)
+recovery-loaded = { $count ->
+    [one] Recovered one entry, use save to keep it
+   *[other] Recovered { $count } entries, use save to keep them
+}
+recovery-unreadable = Failed to read unsaved entries from { $path }, it was left in place
+
+dry-run-header = Dry run, these changes are not saved:
+dry-run-reordered = Entries reordered
+dry-run-focus-changed = Focus mode changed
+dry-run-pomodoros-updated = Pomodoro log updated
+dry-run-would-write = { $count ->
+    [one] Would write { $count } entry to { $path }
+   *[other] Would write { $count } entries to { $path }
+}
+dry-run-would-export = { $count ->
+    [one] Would export { $count } entry to { $path }
+   *[other] Would export { $count } entries to { $path }
+}
+dry-run-would-write-config = Would write the configuration to { $path }
+dry-run-would-import-all = Would replace { $files }
+
+git-pull-failed = Failed to pull { $path } with git. If both sides changed, the histories must be merged by hand
+git-stage-failed = Failed to stage { $path } with git. Is this directory a git repository?
+git-commit-failed = Failed to commit { $path } with git
+git-push-failed = Failed to push { $path } with git, it will be pushed with the next save
+hook-serialize-failed = Failed to serialize data for the { $hook } hook!
+hook-run-failed = Failed to run the { $hook } hook
+hook-exit-status = The { $hook } hook exited with { $status }
+hook-wait-failed = Failed to wait for the { $hook } hook
+webhook-failed = Failed to deliver { $event } webhook: { $error }
+
+capabilities-version = Version: { $version }
+capabilities-features = Features: { $features }
+capabilities-none = none
+capabilities-formats = Formats: { $formats }
+capabilities-backends = Backends: { $backends }
+capabilities-manifest-versions = Manifest versions: { $versions }
+capabilities-locales = Locales: { $locales }
+capabilities-commands = Commands: { $commands }
+capabilities-serialize-failed = Failed to serialize capabilities!
+
+version-commit = Commit: { $commit }
+version-built = Built: { $date }
+version-unknown = unknown
+version-manifest = Manifest version: { $version } ({ $path } has { $saved })
+version-manifest-unreadable = Manifest version: { $version } ({ $path } could not be read)
+version-manifest-missing = Manifest version: { $version } (no { $path } here)
+
+relative-just-now = just now
+relative-minutes-ago = { $count ->
+    [one] a minute ago
+   *[other] { $count } minutes ago
+}
+relative-hours-ago = { $count ->
+    [one] an hour ago
+   *[other] { $count } hours ago
+}
+relative-days-ago = { $count ->
+    [one] yesterday
+   *[other] { $count } days ago
+}
+relative-weeks-ago = { $count ->
+    [one] last week
+   *[other] { $count } weeks ago
+}
+
+no-macros = No macros recorded yet, start one with macro record <name>
+macro-usage = Usage: macro record <name>, macro stop, macro run <name> or macro list
+macro-recording = Recording macro { $name }, finish it with macro stop
+macro-already-recording = Already recording macro { $name }
+macro-not-recording = No macro is being recorded
+macro-empty = Macro { $name } has no commands and was not saved
+macro-saved = { $count ->
+    [one] Saved macro { $name } with one command
+   *[other] Saved macro { $name } with { $count } commands
+}
+macro-not-found = No macro named { $name }
+
+setup-welcome = No { $path } or saved entries were found, so a few settings will be set up now. Leave an answer empty to keep the default.
+setup-locale = Language ({ $locales }), empty to follow the system:
+setup-timezone = Timezone such as Europe/Berlin, empty to follow the system:
+setup-twelve-hour-clock = Show times with a 12-hour clock? (y/n)
+setup-daily-capacity = Daily capacity such as 6h, empty for none:
+setup-git-sync = Commit the state file to the surrounding git repository on every save? (y/n)
+setup-finished = Saved the settings to { $path }, which can be edited at any time
+setup-locale-restart = The language is used from the next start on
+
+tutorial-welcome = This tutorial runs on an empty practice list, so nothing you do here changes your entries or files. Type exit to leave it at any time.
+tutorial-add = Step 1 of 4: add an entry, for example: add Buy bread
+tutorial-list = Step 2 of 4: show your entries with: list
+tutorial-remove = Step 3 of 4: once an entry is done, remove it by its number, for example: remove 0
+tutorial-save = Step 4 of 4: save your entries with: save
+tutorial-step-done = Well done!
+tutorial-try-again = That did not complete this step yet, give it another try
+tutorial-stopped = Left the tutorial, your entries are unchanged
+tutorial-finished = That's it! Type help to see all the other commands
+
+docs-usage = Usage: todotracker [OPTIONS] [COMMAND [ARGUMENTS]]
+docs-options = Options:
+docs-commands = Commands (without one, an interactive session is started):
+docs-files = Entries are kept in { $state } and settings read from { $config }, both in the current directory.
+docs-summary = track todo entries from the command line
+docs-about = Without a command, todotracker starts an interactive session that reads one command per line; several commands can be separated by ;. Given a command, it runs that command against the state file in the current directory and saves the file again if anything changed.
+docs-state-file = The todo entries, time logs and focus mode of the current directory.
+docs-config-file = Settings such as the locale, timezone, hooks, smart lists and macros.
+docs-usage-command = Usage: docs man or docs help
+option-help = Prints this help text
+option-quiet = Silences all logging and, when running a single command, its output
+option-verbose = Logs commands, saves, hooks and git sync steps, with more detail for each extra v
+option-log-file = Appends the log to a file instead of standard error
+option-dry-run = Shows what commands would change without saving anything
+option-read-only = Only allows commands that do not change the entries
+option-sandbox = Starts with example entries and never reads or writes any files
+option-deterministic = Uses a fixed clock, UTC and seeded random choices so output can be compared between runs
+option-seed = Seed of --deterministic, which it implies (0 by default)
+
+no-schedules = No commands are scheduled, add them to schedules in config.ron
+schedule-usage = Usage: schedule list
+schedule-next = next { $time }
+schedule-never = never runs
+schedule-running = Running scheduled command: { $command }
+prompt-skipped = Skipped the prompt "{ $prompt }", because scheduled commands cannot ask for input
+input-required = { $command } reads from the terminal and cannot run as a scheduled command
+cron-field-count = Invalid schedule { $expression }, expected minute, hour, day of month, month and day of week
+cron-invalid-field = Invalid schedule field { $field }
+
+sandbox-started = Sandbox mode: these are example entries and nothing is saved
+sandbox-not-writing = Sandbox mode, not writing { $path }
+sandbox-report = Write the quarterly report
+sandbox-report-description = The numbers are in the shared drive
+sandbox-groceries = Buy groceries
+sandbox-groceries-description = Milk, bread and eggs
+sandbox-supermarket = supermarket
+sandbox-dentist = Call the dentist
+sandbox-bike = Fix the bike's brakes
+sandbox-garage = garage
+sandbox-trip = Plan the weekend trip
+sandbox-trip-description = Pack snacks from the groceries
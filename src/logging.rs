@@ -0,0 +1,32 @@
+use std::fs::OpenOptions;
+use std::sync::Mutex;
+
+use tracing::level_filters::LevelFilter;
+
+use crate::locale::tr;
+
+pub fn init(verbosity: i8, log_file: Option<&str>) {
+	let level = match verbosity {
+		i8::MIN..=-1 => LevelFilter::OFF,
+		0 => LevelFilter::ERROR,
+		1 => LevelFilter::INFO,
+		2 => LevelFilter::DEBUG,
+		_ => LevelFilter::TRACE,
+	};
+
+	let subscriber = tracing_subscriber::fmt()
+		.with_max_level(level)
+		.with_target(false);
+
+	if let Some(path) = log_file {
+		match OpenOptions::new().create(true).append(true).open(path) {
+			Ok(file) => {
+				subscriber.with_ansi(false).with_writer(Mutex::new(file)).init();
+				return;
+			}
+			Err(error) => eprintln!("{}", tr!("log-file-failed", path = path, error = error.to_string())),
+		}
+	}
+
+	subscriber.with_writer(std::io::stderr).init();
+}
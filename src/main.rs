@@ -1,91 +1,748 @@
-use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
 
+use chrono::{DateTime, NaiveDate, Utc};
+use dialoguer::FuzzySelect;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use strum::{EnumIter, IntoEnumIterator};
 
-const STATE_MANIFEST_VERSION: usize = 1;
+use crate::commands::{CommandError, CommandRegistry};
+use crate::filter::Filter;
+use crate::locale::tr;
+use crate::output::{out, outln};
+use crate::timezone::Zone;
+
+mod autosave;
+mod bundle;
+mod commands;
+#[cfg(unix)]
+mod control;
+#[cfg(all(target_os = "linux", feature = "dbus"))]
+mod dbus;
+mod docs;
+mod dry_run;
+mod export;
+mod filter;
+mod git_sync;
+mod history;
+mod hooks;
+mod import;
+mod locale;
+mod logging;
+mod mcp;
+mod output;
+mod sandbox;
+mod schedule;
+mod script;
+mod server;
+mod setup;
+mod timezone;
+#[cfg(feature = "url-titles")]
+mod url_title;
+mod watch;
+mod webhook;
+
+const STATE_MANIFEST_VERSION: usize = 13;
+const STATE_FILE_PATH: &str = "state.ron";
+const CONFIG_FILE_PATH: &str = "config.ron";
+const DEFAULT_POMODORO_MINUTES: i64 = 25;
+const DEFAULT_FOCUS_COUNT: usize = 3;
+const DEFAULT_AUTOSAVE_MINUTES: i64 = 1;
+const DEFAULT_LINK_RELATION: &str = "relates-to";
+const DEFAULT_SERVER_HOST: &str = "127.0.0.1";
+const DEFAULT_SERVER_PORT: u16 = 8080;
+
+const DETERMINISTIC_EPOCH: i64 = 1_704_067_200;
+
+static SEED: OnceLock<u64> = OnceLock::new();
+
+thread_local! {
+	static WRITTEN_MANIFEST_VERSION: Cell<usize> = const { Cell::new(STATE_MANIFEST_VERSION) };
+	static INTERACTIVE: Cell<bool> = const { Cell::new(true) };
+}
 
 fn main() {
+	let mut arguments = std::env::args().skip(1).collect::<Vec<String>>();
+	let mut verbosity = 0;
+	let mut quiet = false;
+	let mut log_file = None;
+	let mut dry_run = false;
+	let mut read_only = false;
+	let mut sandbox = false;
+	let mut seed = None;
+	let mut help = false;
+
+	while let Some(flag) = arguments.first().cloned() {
+		match flag.as_str() {
+			"-q" | "--quiet" => {
+				verbosity = -1;
+				quiet = true;
+			}
+			"-v" | "--verbose" => verbosity += 1,
+			"-vv" => verbosity += 2,
+			"-vvv" => verbosity += 3,
+			"--dry-run" => dry_run = true,
+			"--read-only" => read_only = true,
+			"--sandbox" => sandbox = true,
+			"--deterministic" => seed = seed.or(Some(0)),
+			"-h" | "--help" => help = true,
+			"--log-file" => {
+				arguments.remove(0);
+
+				if arguments.is_empty() {
+					eprintln!("{}", tr!("missing-log-file-path"));
+					std::process::exit(CommandError::InvalidInput.exit_code());
+				}
+
+				log_file = Some(arguments.remove(0));
+				continue;
+			}
+			"--seed" => {
+				arguments.remove(0);
+
+				let Some(value) = arguments.first().and_then(|value| value.parse::<u64>().ok()) else {
+					eprintln!("{}", tr!("invalid-seed"));
+					std::process::exit(CommandError::InvalidInput.exit_code());
+				};
+
+				seed = Some(value);
+				arguments.remove(0);
+				continue;
+			}
+			_ => break,
+		}
+
+		arguments.remove(0);
+	}
+
+	logging::init(verbosity, log_file.as_deref());
+
+	if let Some(seed) = seed {
+		tracing::debug!("Running deterministically with seed {seed}");
+		SEED.set(seed).unwrap_or_default();
+	}
+
 	let mut state = State::new();
 
-	println!("Todo Tracker");
+	if !sandbox {
+		state.config = Config::load();
+	}
+	locale::init(state.config.locale.as_deref());
+
+	if help {
+		out!("{}", docs::help());
+		return;
+	}
+
+	if let Err(name) = Zone::parse(state.config.timezone.as_deref()) {
+		tracing::warn!("Unknown timezone {name:?} in {CONFIG_FILE_PATH}");
+		eprintln!("{}", tr!("timezone-invalid", name = name.as_str()));
+	}
+
+	if let Some(capacity) = state.config.daily_capacity.as_deref().filter(|capacity| parse_duration(capacity).is_none()) {
+		tracing::warn!("Invalid daily_capacity {capacity:?} in {CONFIG_FILE_PATH}");
+		eprintln!("{}", tr!("daily-capacity-invalid", capacity = capacity));
+	}
+
+	if let Some(interval) = state.config.autosave_interval.as_deref().filter(|interval| parse_duration(interval).is_none()) {
+		tracing::warn!("Invalid autosave_interval {interval:?} in {CONFIG_FILE_PATH}");
+		eprintln!("{}", tr!("autosave-interval-invalid", interval = interval));
+	}
+
+	for schedule in &state.config.schedules {
+		if let Err(error) = schedule::Cron::parse(&schedule.cron) {
+			tracing::warn!("Invalid schedule {:?} in {CONFIG_FILE_PATH}", schedule.cron);
+			eprintln!("{error}");
+		}
+	}
+
+	#[cfg(not(feature = "url-titles"))]
+	if state.config.fetch_url_titles {
+		tracing::warn!("fetch_url_titles is set, but this build has no url-titles feature");
+		eprintln!("{}", tr!("url-titles-unsupported"));
+	}
+
+	state.dry_run = dry_run || sandbox;
+	state.read_only = read_only;
+	state.sandbox = sandbox;
+
+	if sandbox {
+		sandbox::populate(&mut state);
+	}
+
+	if !arguments.is_empty() {
+		if quiet {
+			output::silence();
+		}
+
+		let mut newer = false;
 
-	while !state.exit {
-		println!("Enter a command:");
+		if !sandbox && PathBuf::from(STATE_FILE_PATH).exists() {
+			let saved = std::fs::read_to_string(STATE_FILE_PATH)
+				.map_err(|error| {
+					eprintln!("{}", tr!("state-read-failed-path", path = STATE_FILE_PATH, error = error.to_string()));
+					CommandError::Io
+				})
+				.and_then(|data| {
+					parse_state(&data, STATE_FILE_PATH).map_err(|error| {
+						eprintln!("{error}");
+						CommandError::InvalidInput
+					})
+				});
+
+			match saved {
+				Ok(saved) => {
+					if saved.manifest_version > STATE_MANIFEST_VERSION {
+						eprintln!("{}", tr!("manifest-newer"));
+						newer = true;
+					}
+
+					state.entries = saved.entries;
+					state.pomodoros = saved.pomodoros;
+					state.focus = saved.focus;
+					state.planned = saved.planned;
+					state.last_id = saved.last_id;
+					state.assign_missing_ids();
+				}
+				Err(error) => std::process::exit(error.exit_code()),
+			}
+		}
+
+		let mut result = run_line(&mut state, &arguments.join(" "));
+
+		if state.modified && !sandbox && newer {
+			eprintln!("{}", tr!("manifest-newer-not-writing", path = STATE_FILE_PATH));
+			result = result.and(Err(CommandError::Rejected));
+		} else if state.modified && !sandbox && !write_state_file(&state) {
+			result = result.and(Err(CommandError::Io));
+		}
+
+		if let Err(error) = result {
+			std::process::exit(error.exit_code());
+		}
+
+		return;
+	}
+
+	let first_run = !PathBuf::from(CONFIG_FILE_PATH).exists() && !PathBuf::from(STATE_FILE_PATH).exists();
+
+	if first_run && !dry_run && !read_only && !sandbox && std::io::stdin().is_terminal() {
+		setup::run(&mut state);
+	}
+
+	autosave::recover(&mut state, autosave::EMERGENCY_FILE_PATH);
+	autosave::recover(&mut state, autosave::AUTOSAVE_FILE_PATH);
+
+	if !sandbox {
+		state.command_history = history::load();
+	}
+
+	let state = Arc::new(Mutex::new(state));
+
+	#[cfg(unix)]
+	let _control_socket = (!sandbox).then(|| control::listen(Arc::clone(&state)));
+	#[cfg(all(target_os = "linux", feature = "dbus"))]
+	let _dbus = (!sandbox).then(|| dbus::serve(Arc::clone(&state)));
+	let _watcher = (!sandbox).then(|| watch::watch(Arc::clone(&state)));
+	autosave::on_termination(Arc::clone(&state));
+	autosave::periodically(Arc::clone(&state));
+	schedule::run(Arc::clone(&state));
+
+	outln!("{}", tr!("title"));
+
+	if sandbox {
+		outln!("{}", tr!("sandbox-started"));
+	}
+
+	while !state.lock().unwrap_or_else(PoisonError::into_inner).exit {
+		if let Some(prompt) = state.lock().unwrap_or_else(PoisonError::into_inner).prompt() {
+			out!("{prompt}");
+			std::io::stdout().flush().unwrap_or_default();
+		} else {
+			outln!("{}", tr!("enter-command"));
+		}
 
 		let mut buffer = String::new();
 		std::io::stdin().read_line(&mut buffer).unwrap_or_default();
-		buffer = buffer.trim_end().to_string();
 
-		let command = Command::from(buffer);
+		let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
 
-		match command {
-			Command::Add => {
-				println!("Name of todo entry:");
+		let Some(line) = history::expand(&state.command_history, buffer.trim()) else {
+			continue;
+		};
 
-				let mut name = String::new();
-				std::io::stdin().read_line(&mut name).unwrap_or_default();
-				name = name.trim_end().to_string();
+		if line != buffer.trim() {
+			outln!("{line}");
+		}
 
-				println!("Description of todo entry:");
+		if sandbox && !line.is_empty() {
+			state.command_history.push(line.clone());
+		} else if !line.is_empty() {
+			history::record(&mut state.command_history, &line);
+		}
 
-				let mut description = String::new();
-				std::io::stdin().read_line(&mut description).unwrap_or_default();
-				description = description.trim_end().to_string();
+		run_line(&mut state, &line).unwrap_or_default();
+	}
 
-				command.execute(&mut state, CommandState::add(name, description));
-			}
-			Command::Remove => {
-				println!("Index of entry to remove:");
-
-				let mut index = String::new();
-				std::io::stdin().read_line(&mut index).unwrap_or_default();
-				let index = index.trim_end().to_string().parse::<usize>().unwrap_or_else(
-					|_| {
-						eprintln!("No entry found at that index");
-						usize::MAX
-					}
-				);
+	autosave::finish(&state.lock().unwrap_or_else(PoisonError::into_inner));
+}
+
+fn run_line(state: &mut State, line: &str) -> Result<(), CommandError> {
+	let commands = line.split(';').map(str::trim).filter(|command| !command.is_empty()).collect::<Vec<&str>>();
 
-				command.execute(&mut state, CommandState::remove(index));
+	for (position, command) in commands.iter().enumerate() {
+		if let Err(error) = run_command(state, command) {
+			if position + 1 < commands.len() {
+				eprintln!("{}", tr!("chain-stopped", count = commands.len() - position - 1));
 			}
-			_ => command.execute(&mut state, CommandState::empty())
+
+			return Err(error);
+		}
+
+		if state.exit {
+			break;
+		}
+	}
+
+	Ok(())
+}
+
+fn run_command(state: &mut State, line: &str) -> Result<(), CommandError> {
+	let mut arguments = line.split_whitespace();
+	let key = arguments.next().unwrap_or_default();
+	let _span = tracing::info_span!("command", key).entered();
+
+	tracing::info!("Running {line:?}");
+
+	let before = (state.dry_run && !state.sandbox).then(|| state.clone());
+	let registry = CommandRegistry::builtin();
+	let command = registry.find(key);
+	let unchanged = command.is_some_and(|command| !command.read_only())
+		.then(|| (state.entries.clone(), state.pomodoros.clone(), state.focus, state.planned.clone()));
+
+	let result = match command {
+		Some(command) if state.read_only && !command.read_only() => {
+			eprintln!("{}", tr!("read-only-rejected", command = command.key()));
+			Err(CommandError::Rejected)
+		}
+		Some(command) => command.execute(state, arguments),
+		None => {
+			eprintln!("{}", tr!("unknown-command"));
+			Err(CommandError::InvalidInput)
+		}
+	};
+
+	if let Some(before) = before {
+		dry_run::report(&before, state);
+	}
+
+	if let Some((entries, pomodoros, focus, planned)) = unchanged {
+		if entries != state.entries || pomodoros != state.pomodoros || focus != state.focus || planned != state.planned {
+			state.modified = true;
+		}
+	}
+
+	match (&result, &mut state.macro_recording) {
+		(Err(error), _) => tracing::info!("{key} failed: {error:?}"),
+		(Ok(()), Some((_, commands))) if !key.eq_ignore_ascii_case("macro") => commands.push(line.to_string()),
+		_ => {}
+	}
+
+	result
+}
+
+fn argument_or_prompt(argument: Option<&str>, prompt: &str) -> String {
+	if let Some(argument) = argument {
+		return argument.to_string();
+	}
+
+	if !is_interactive() {
+		eprintln!("{}", tr!("prompt-skipped", prompt = prompt));
+		return String::new();
+	}
+
+	outln!("{prompt}");
+
+	let mut buffer = String::new();
+	std::io::stdin().read_line(&mut buffer).unwrap_or_default();
+	buffer.trim_end().to_string()
+}
+
+fn index_or_pick(state: &State, argument: Option<&str>, prompt: &str, picker_prompt: &str) -> Result<Option<usize>, CommandError> {
+	if argument.is_some() || state.entries.is_empty() || !is_interactive() || !std::io::stdin().is_terminal() {
+		return state.resolve_index(&argument_or_prompt(argument, prompt)).map(Some);
+	}
+
+	let items = state.entries.iter()
+		.enumerate()
+		.map(|(index, entry)| format!("{index} - {}: {}", entry.name, entry.description))
+		.collect::<Vec<String>>();
+
+	let picked = FuzzySelect::new()
+		.with_prompt(picker_prompt)
+		.items(&items)
+		.interact_opt()
+		.unwrap_or_else(|error| {
+			tracing::warn!("Failed to show the entry picker: {error}");
+			None
+		});
+
+	if picked.is_none() {
+		outln!("{}", tr!("pick-cancelled"));
+	}
+
+	Ok(picked)
+}
+
+fn confirm(prompt: &str) -> bool {
+	if !is_interactive() {
+		eprintln!("{}", tr!("prompt-skipped", prompt = prompt));
+		return false;
+	}
+
+	loop {
+		outln!("{prompt}");
+
+		let mut buffer = String::new();
+
+		if std::io::stdin().read_line(&mut buffer).unwrap_or_default() == 0 {
+			return false;
+		}
+
+		match buffer.trim_end() {
+			"y" | "Y" | "yes" | "Yes" | "YES" => return true,
+			"n" | "N" | "no" | "No" | "NO" => return false,
+			_ => eprintln!("{}", tr!("unknown-input")),
+		}
+	}
+}
+
+fn read_state_file() -> Option<State> {
+	let data = std::fs::read_to_string(STATE_FILE_PATH).map_err(|error| {
+		tracing::warn!("Failed to read {STATE_FILE_PATH}: {error}");
+	}).ok()?;
+
+	let state = parse_state(&data, STATE_FILE_PATH).map_err(|error| {
+		tracing::warn!("Failed to parse {STATE_FILE_PATH}: {error}");
+	}).ok()?;
+
+	tracing::info!("Read {} entries from {STATE_FILE_PATH}", state.entries.len());
+
+	Some(state)
+}
+
+fn now() -> DateTime<Utc> {
+	match SEED.get() {
+		Some(seed) => DateTime::from_timestamp(DETERMINISTIC_EPOCH + i64::try_from(*seed).unwrap_or_default(), 0)
+			.unwrap_or_default(),
+		None => Utc::now(),
+	}
+}
+
+fn is_deterministic() -> bool {
+	SEED.get().is_some()
+}
+
+fn is_interactive() -> bool {
+	INTERACTIVE.get()
+}
+
+fn rng() -> StdRng {
+	match SEED.get() {
+		Some(seed) => StdRng::seed_from_u64(*seed),
+		None => StdRng::from_os_rng(),
+	}
+}
+
+fn newer_than_written<const VERSION: usize, T>(_: &T) -> bool {
+	WRITTEN_MANIFEST_VERSION.get() < VERSION
+}
+
+fn parse_state(data: &str, path: &str) -> Result<State, String> {
+	let error = match ron::from_str::<State>(data) {
+		Ok(state) => return Ok(state),
+		Err(error) => error,
+	};
+
+	let mut message = tr!(
+		"state-parse-error",
+		path = path,
+		line = error.position.line,
+		column = error.position.col,
+		error = error.code.to_string()
+	);
+
+	let saved = Regex::new(r"manifest_version\s*:\s*(\d+)").ok()
+		.and_then(|pattern| pattern.captures(data)?.get(1)?.as_str().parse::<usize>().ok());
+
+	let hint = match (&error.code, saved) {
+		(_, Some(saved)) if saved > STATE_MANIFEST_VERSION => {
+			Some(tr!("state-parse-hint-newer", saved = saved, current = STATE_MANIFEST_VERSION))
+		}
+		(ron::Error::MissingStructField { field, .. }, _) => {
+			Some(tr!("state-parse-hint-missing-field", field = field.to_string()))
+		}
+		(ron::Error::NoSuchEnumVariant { found, .. }, _) => {
+			Some(tr!("state-parse-hint-unknown-value", value = found.as_str()))
+		}
+		_ => None,
+	};
+
+	if let Some(hint) = hint {
+		message.push('\n');
+		message.push_str(&hint);
+	}
+
+	Err(message)
+}
+
+fn write_config_file(state: &State) -> bool {
+	if state.sandbox {
+		eprintln!("{}", tr!("sandbox-not-writing", path = CONFIG_FILE_PATH));
+		return true;
+	}
+
+	if state.dry_run {
+		eprintln!("{}", tr!("dry-run-would-write-config", path = CONFIG_FILE_PATH));
+		return true;
+	}
+
+	if state.read_only {
+		eprintln!("{}", tr!("read-only-not-writing", path = CONFIG_FILE_PATH));
+		return false;
+	}
+
+	let data = match ron::ser::to_string_pretty(&state.config, ron::ser::PrettyConfig::default()) {
+		Ok(data) => data,
+		Err(error) => {
+			tracing::warn!("Failed to serialize the configuration: {error}");
+			eprintln!("{}", tr!("config-serialize-failed"));
+			return false;
+		}
+	};
+
+	std::fs::write(CONFIG_FILE_PATH, data).map_err(|error| {
+		tracing::warn!("Failed to write {CONFIG_FILE_PATH}: {error}");
+		eprintln!("{}", tr!("config-write-failed", path = CONFIG_FILE_PATH));
+	}).is_ok()
+}
+
+fn write_state_file(state: &State) -> bool {
+	if state.sandbox {
+		eprintln!("{}", tr!("sandbox-not-writing", path = STATE_FILE_PATH));
+		return true;
+	}
+
+	if state.dry_run {
+		eprintln!("{}", tr!("dry-run-would-write", count = state.entries.len(), path = STATE_FILE_PATH));
+		return true;
+	}
+
+	if state.read_only {
+		eprintln!("{}", tr!("read-only-not-writing", path = STATE_FILE_PATH));
+		return false;
+	}
+
+	let data = match ron::ser::to_string_pretty(state, ron::ser::PrettyConfig::default()) {
+		Ok(data) => data,
+		Err(error) => {
+			tracing::warn!("Failed to serialize state: {error}");
+			eprintln!("{}", tr!("state-serialize-failed"));
+			return false;
 		}
+	};
+
+	let written = std::fs::write(STATE_FILE_PATH, data).map_err(|error| {
+		tracing::warn!("Failed to write {STATE_FILE_PATH}: {error}");
+		eprintln!("{}", tr!("state-write-failed"));
+	}).is_ok();
+
+	if written {
+		tracing::info!("Wrote {} entries to {STATE_FILE_PATH}", state.entries.len());
+	}
+
+	if written && state.config.git_sync {
+		git_sync::commit();
 	}
+
+	if written {
+		hooks::run(state.config.hooks.on_save.as_ref(), "on_save", &state.entries);
+	}
+
+	written
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+fn write_backup_file(state: &State) -> Option<String> {
+	let stamp = now().format("%Y%m%d-%H%M%S").to_string();
+	let path = (1..)
+		.map(|number| match number {
+			1 => format!("state.backup-{stamp}.ron"),
+			number => format!("state.backup-{stamp}-{number}.ron"),
+		})
+		.find(|path| !PathBuf::from(path).exists())
+		.unwrap_or_default();
+
+	let written = ron::ser::to_string_pretty(state, ron::ser::PrettyConfig::default())
+		.map_err(|error| error.to_string())
+		.and_then(|data| std::fs::write(&path, data).map_err(|error| error.to_string()));
+
+	match written {
+		Ok(()) => {
+			tracing::info!("Backed up {} entries to {path}", state.entries.len());
+			Some(path)
+		}
+		Err(error) => {
+			tracing::warn!("Failed to write {path}: {error}");
+			None
+		}
+	}
+}
+
+fn parse_index(value: &str) -> Result<usize, CommandError> {
+	value.parse::<usize>().map_err(|_| {
+		eprintln!("{}", tr!("invalid-index"));
+		CommandError::InvalidInput
+	})
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 struct State {
 	pub entries: Vec<TodoEntry>,
 	pub exit: bool,
 	pub manifest_version: usize,
+	#[serde(default, skip_serializing_if = "newer_than_written::<3, _>")]
+	pub pomodoros: PomodoroLog,
+	#[serde(default, skip_serializing_if = "newer_than_written::<4, _>")]
+	pub focus: Option<usize>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<12, _>")]
+	pub planned: Vec<usize>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<13, _>")]
+	pub last_id: usize,
+	#[serde(skip)]
+	pub config: Config,
+	#[serde(skip)]
+	pub dry_run: bool,
+	#[serde(skip)]
+	pub read_only: bool,
+	#[serde(skip)]
+	pub selection: Vec<usize>,
+	#[serde(skip)]
+	pub command_history: Vec<String>,
+	#[serde(skip)]
+	pub macro_recording: Option<(String, Vec<String>)>,
+	#[serde(skip)]
+	pub modified: bool,
+	#[serde(skip)]
+	pub sandbox: bool,
+	#[serde(skip)]
+	pub stale: bool,
 }
 
-struct CommandState {
-	index: Option<usize>,
-	name: Option<String>,
-	description: Option<String>,
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct Config {
+	pub webhooks: Vec<String>,
+	pub git_sync: bool,
+	pub vault: Option<String>,
+	pub hooks: hooks::Hooks,
+	pub locale: Option<String>,
+	pub twelve_hour_clock: bool,
+	pub timezone: Option<String>,
+	pub daily_capacity: Option<String>,
+	pub smart_lists: BTreeMap<String, String>,
+	pub macros: BTreeMap<String, Vec<String>>,
+	pub prompt: Option<String>,
+	pub autosave_interval: Option<String>,
+	pub fetch_url_titles: bool,
+	pub save_on_exit: bool,
+	pub duplicates: DuplicatePolicy,
+	pub schedules: Vec<schedule::ScheduledCommand>,
+}
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+struct PomodoroLog {
+	pub completed: usize,
+	pub streak: usize,
+	pub best_streak: usize,
+	pub last_day: Option<NaiveDate>,
 }
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Serialize, Deserialize)]
 struct TodoEntry {
 	pub name: String,
 	pub description: String,
+	#[serde(default, skip_serializing_if = "newer_than_written::<2, _>")]
+	pub time_log: Vec<TimeInterval>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<2, _>")]
+	pub tracking_since: Option<DateTime<Utc>>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<5, _>")]
+	pub source: Option<EntrySource>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<6, _>")]
+	pub history: Vec<EntryChange>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<7, _>")]
+	pub id: usize,
+	#[serde(default, skip_serializing_if = "newer_than_written::<7, _>")]
+	pub links: Vec<EntryLink>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<8, _>")]
+	pub estimate_minutes: Option<i64>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<9, _>")]
+	pub assignee: Option<String>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<10, _>")]
+	pub location: Option<String>,
+	#[serde(default, skip_serializing_if = "newer_than_written::<11, _>")]
+	pub color: Option<Color>,
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Serialize, Deserialize)]
+struct EntrySource {
+	pub path: String,
+	pub line: usize,
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Serialize, Deserialize)]
+struct EntryLink {
+	pub target: usize,
+	pub relation: String,
 }
 
-#[derive(EnumIter, Ord, PartialOrd, Eq, PartialEq)]
-enum Command {
-	Help,
-	List,
-	Add,
-	Remove,
-	Clear,
-	Save,
-	Load,
-	Exit,
-	Unknown,
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Serialize, Deserialize)]
+struct EntryChange {
+	pub field: String,
+	pub old: String,
+	pub new: String,
+	pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Color {
+	Red,
+	Green,
+	Yellow,
+	Blue,
+	Magenta,
+	Cyan,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum DuplicatePolicy {
+	#[default]
+	Allow,
+	Reject,
+	Confirm,
+	Merge,
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Serialize, Deserialize)]
+struct TimeInterval {
+	pub start: DateTime<Utc>,
+	pub end: DateTime<Utc>,
 }
 
 impl State {
@@ -94,308 +751,392 @@ impl State {
 			entries: Vec::<TodoEntry>::new(),
 			exit: false,
 			manifest_version: STATE_MANIFEST_VERSION,
+			pomodoros: PomodoroLog::default(),
+			focus: None,
+			planned: Vec::new(),
+			last_id: 0,
+			config: Config::default(),
+			dry_run: false,
+			read_only: false,
+			selection: Vec::new(),
+			command_history: Vec::new(),
+			macro_recording: None,
+			modified: false,
+			sandbox: false,
+			stale: false,
 		}
 	}
-}
 
-impl TodoEntry {
-	fn new(name: String, description: String) -> Self {
-		TodoEntry {
-			name,
-			description
+	fn add_entry(&mut self, mut entry: TodoEntry) -> usize {
+		entry.id = self.next_id();
+		self.last_id = entry.id;
+		self.entries.push(entry);
+		self.modified = true;
+
+		let index = self.entries.len() - 1;
+
+		if !self.dry_run {
+			webhook::send(&self.config.webhooks, "added", index, &self.entries[index]);
+			hooks::run(self.config.hooks.on_add.as_ref(), "on_add", &self.entries[index]);
 		}
+
+		index
 	}
-}
 
-impl Command {
-	pub fn key(&self) -> &str {
-		match self {
-			Command::Help => "help",
-			Command::List => "list",
-			Command::Add => "add",
-			Command::Remove => "remove",
-			Command::Clear => "clear",
-			Command::Save => "save",
-			Command::Load => "load",
-			Command::Exit => "exit",
-			Command::Unknown => unreachable!(),
+	fn import_entries(&mut self, entries: Vec<TodoEntry>) {
+		let first = self.next_id();
+
+		let ids = entries.iter()
+			.enumerate()
+			.map(|(position, entry)| (entry.id, first + position))
+			.collect::<BTreeMap<usize, usize>>();
+
+		for mut entry in entries {
+			entry.tracking_since = None;
+			entry.links = entry.links.into_iter()
+				.filter_map(|link| {
+					Some(EntryLink {
+						target: *ids.get(&link.target)?,
+						relation: link.relation,
+					})
+				})
+				.collect();
+
+			self.add_entry(entry);
 		}
 	}
 
-	pub fn description(&self, ) -> &str {
-		match self {
-			Command::Help => "Displays a help message",
-			Command::List => "Lists all todo entries",
-			Command::Add => "Adds a new todo entry",
-			Command::Remove => "Removes a todo entry by its index",
-			Command::Clear => "Clears all todo entries",
-			Command::Save => "Saves the current todo entries to a file",
-			Command::Load => "Loads the todo entries from a file",
-			Command::Exit => "Exits the program",
-			Command::Unknown => unreachable!()
+	fn remove_entry(&mut self, index: usize) -> Option<TodoEntry> {
+		if index >= self.entries.len() {
+			return None;
 		}
-	}
 
-	#[allow(clippy::too_many_lines)]
-	pub fn execute(self, state: &mut State, command_state: CommandState) {
-		match self {
-			Command::Help => {
-				for command in Command::iter() {
-					if command == Command::Unknown { break }
-					println!("{command} ({}) : {}", command.key(), command.description());
-				}
-			}
-			Command::List => {
-				if state.entries.is_empty() {
-					println!("Nothing to list");
-				} else {
-					for entry in &state.entries {
-						println!(
-							"{} - {}: {}",
-							state.entries.binary_search(entry).unwrap_or_else(|_| {
-								eprintln!("Failed to get index of entry!");
-								usize::MAX
-							}),
-							entry.name,
-							entry.description
-						);
-					}
-				}
-			}
-			Command::Add => {
-				if let (
-					Some(name),
-					Some(description)
-				) = (
-					command_state.name,
-					command_state.description
-				) {
-					state.entries.push(TodoEntry::new(name, description));
-				} else if cfg!(debug_assertions) {
-					eprintln!("command_state.name and command_state.description \
-					are required to be Some for Command::Add");
-				}
-			}
-			Command::Remove => {
-				if let Some(index) = command_state.index {
-					if state.entries.get(index).is_some() {
-						println!("Removed entry {}", state.entries.get(index).unwrap().name);
-						state.entries.remove(index);
-					} else {
-						eprintln!("No todo entry found at index {index}");
-					}
-				} else if cfg!(debug_assertions) {
-					eprintln!("command_state.index is required to be Some for Command::Remove");
-				}
-			}
-			Command::Clear => {
-				if state.entries.is_empty() {
-					println!("Nothing to clear");
-				} else {
-					let entries_count = state.entries.len();
-					state.entries.clear();
-					println!(
-						"{entries_count} {} cleared",
-						if entries_count > 1 {
-							"entries"
-						} else {
-							"entry"
-						}
-					);
-				}
-			}
-			Command::Save => {
-				if state.entries.is_empty() {
-					println!("Nothing to save");
-					return;
-				}
+		let entry = self.entries.remove(index);
+		self.modified = true;
 
-				let data = ron::ser::to_string_pretty(
-					state,
-					ron::ser::PrettyConfig::default()
-				).unwrap_or_else(|_| {
-					eprintln!("Failed to save state to a file!");
-					String::new()
-				});
+		for other in &mut self.entries {
+			other.links.retain(|link| link.target != entry.id);
+		}
 
-				std::fs::write("state.ron", data).unwrap_or_else(|_| {
-					eprintln!("Failed to write state data to file!");
-				});
+		self.planned.retain(|id| *id != entry.id);
+		self.selection.retain(|id| *id != entry.id);
 
-				if PathBuf::from("state.ron").exists() {
-					println!("Saved state data to state.ron");
-				}
-			}
-			Command::Load => {
-				let mut should_abort = false;
-
-				if PathBuf::from("state.ron").exists() {
-					let data = ron::from_str::<State>(
-						&std::fs::read_to_string("state.ron").unwrap_or_else(|_| {
-							eprintln!("Failed to read state data from file. \
-							Are you sure it exists?");
-							should_abort = true;
-							String::new()
-						})
-					).unwrap_or_else(|_| {
-						eprintln!("Failed to parse state data from file!");
-						should_abort = true;
-						State::new()
-					});
-					
-					match data.manifest_version.cmp(&state.manifest_version) {
-						Ordering::Less => {
-							eprintln!("This save file has an old manifest version, \
-							and may not load correctly");
-						}
-						Ordering::Greater => {
-							eprintln!("This save file has been created with a newer version, \
-							and may not load correctly");
-						}
-						Ordering::Equal => {}
-					}
+		if !self.dry_run {
+			webhook::send(&self.config.webhooks, "removed", index, &entry);
+		}
 
-					if data.entries != state.entries && !state.entries.is_empty() {
-						let mut valid = false;
-
-						while !valid {
-							println!("Override current entries? (y/n)");
-
-							let mut buffer = String::new();
-							std::io::stdin().read_line(&mut buffer).unwrap_or_default();
-							let buffer = buffer.trim_end();
-
-							match buffer {
-								"y" | "Y" | "yes" | "Yes" | "YES" => {
-									valid = true;
-								},
-								"n" | "N" | "no" | "No" | "NO" => {
-									return;
-								},
-								_ => {
-									valid = false;
-									eprintln!("Unknown input");
-								}
-							}
-						}
-					}
+		Some(entry)
+	}
 
-					if should_abort {
-						eprintln!("Due to one or more previous errors, \
-						a state file will not be created");
-						return;
-					}
+	fn next_id(&self) -> usize {
+		self.entries.iter().map(|entry| entry.id).chain([self.last_id]).max().unwrap_or_default() + 1
+	}
 
-					state.entries = data.entries;
-					println!("Loaded {} entries from state file", state.entries.len());
-				} else {
-					eprintln!("No state data file found at that location");
-				}
+	fn assign_missing_ids(&mut self) {
+		for index in 0..self.entries.len() {
+			if self.entries[index].id == 0 {
+				self.entries[index].id = self.next_id();
+				self.last_id = self.entries[index].id;
 			}
-			Command::Exit => {
-				if PathBuf::from("state.ron").exists() {
-					let data = ron::from_str::<State>(
-						&std::fs::read_to_string("state.ron").unwrap_or_else(|_| {
-							eprintln!("Failed to read state data file");
-							String::new()
-						})
-					).unwrap_or_else(|_| {
-						eprintln!("Failed to parse state data from file!");
-						State::new()
-					});
-
-					if state.entries != data.entries {
-						let mut valid = false;
-
-						while !valid {
-							println!("A save file exists, but you have unsaved data. \
-							Are you sure you want to quit? (y/n)");
-
-							let mut buffer = String::new();
-							std::io::stdin().read_line(&mut buffer).unwrap_or_default();
-							let buffer = buffer.trim_end();
-
-							match buffer {
-								"y" | "Y" | "yes" | "Yes" | "YES" => {
-									valid = true;
-								},
-								"n" | "N" | "no" | "No" | "NO" => {
-									return;
-								},
-								_ => {
-									valid = false;
-									eprintln!("Unknown input");
-								}
-							}
-						}
-					}
-				}
+		}
+	}
 
-				state.exit = true;
-			}
-			Command::Unknown => {
-				eprintln!("Unknown command");
-			}
+	fn resolve_index(&self, value: &str) -> Result<usize, CommandError> {
+		let Some(number) = value.strip_prefix('#') else {
+			return parse_index(value);
+		};
+
+		number.parse::<usize>().ok()
+			.and_then(|number| number.checked_sub(1))
+			.and_then(|number| self.selection.get(number))
+			.and_then(|id| self.position_of(*id))
+			.ok_or_else(|| {
+				eprintln!("{}", tr!("invalid-result", result = value));
+				CommandError::InvalidInput
+			})
+	}
+
+	fn position_of(&self, id: usize) -> Option<usize> {
+		self.entries.iter().position(|entry| entry.id == id)
+	}
+
+	fn focused(&self) -> Option<Vec<(usize, &TodoEntry)>> {
+		if !self.planned.is_empty() {
+			return Some(
+				self.entries.iter()
+					.enumerate()
+					.filter(|(_, entry)| self.planned.contains(&entry.id))
+					.collect()
+			);
 		}
+
+		self.focus.map(|count| self.entries.iter().enumerate().take(count).collect())
+	}
+
+	fn prompt(&self) -> Option<String> {
+		let template = self.config.prompt.as_deref()?;
+		let tracking = self.entries.iter()
+			.find(|entry| entry.tracking_since.is_some())
+			.map_or("", |entry| entry.name.as_str());
+		let recording = self.macro_recording.as_ref().map_or("", |(name, _)| name.as_str());
+		let modified = if self.modified { "*" } else { "" };
+
+		Some(
+			template
+				.replace("{count}", &self.entries.len().to_string())
+				.replace("{focused}", &self.focused().map_or(self.entries.len(), |entries| entries.len()).to_string())
+				.replace("{tracking}", tracking)
+				.replace("{recording}", recording)
+				.replace("{modified}", modified)
+		)
+	}
+
+	fn backlinks(&self, id: usize) -> Vec<(usize, &EntryLink)> {
+		self.entries.iter()
+			.enumerate()
+			.flat_map(|(index, entry)| entry.links.iter().map(move |link| (index, link)))
+			.filter(|(_, link)| link.target == id)
+			.collect()
 	}
 }
 
-impl Display for Command {
-	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		match self {
-			Command::Help => write!(f, "Help"),
-			Command::List => write!(f, "List"),
-			Command::Add => write!(f, "Add"),
-			Command::Remove => write!(f, "Remove"),
-			Command::Clear => write!(f, "Clear"),
-			Command::Save => write!(f, "Save"),
-			Command::Load => write!(f, "Load"),
-			Command::Exit => write!(f, "Exit"),
-			Command::Unknown => write!(f, "Unknown Command"),
+impl Config {
+	fn load() -> Self {
+		if !PathBuf::from(CONFIG_FILE_PATH).exists() {
+			return Config::default();
 		}
+
+		std::fs::read_to_string(CONFIG_FILE_PATH)
+			.map_err(|error| error.to_string())
+			.and_then(|data| ron::from_str::<Config>(&data).map_err(|error| error.to_string()))
+			.unwrap_or_else(|error| {
+				tracing::warn!("Failed to load {CONFIG_FILE_PATH}: {error}");
+				eprintln!("{}", tr!("config-parse-failed", path = CONFIG_FILE_PATH));
+				Config::default()
+			})
+	}
+
+	fn zone(&self) -> Zone {
+		Zone::parse(self.timezone.as_deref()).unwrap_or(Zone::System)
+	}
+
+	fn daily_capacity(&self) -> Option<chrono::Duration> {
+		self.daily_capacity.as_deref().and_then(parse_duration)
+	}
+
+	fn autosave_interval(&self) -> Option<chrono::Duration> {
+		self.autosave_interval.as_deref()
+			.map_or(Some(chrono::Duration::minutes(DEFAULT_AUTOSAVE_MINUTES)), parse_duration)
+			.filter(|interval| *interval > chrono::Duration::zero())
+	}
+
+	fn smart_list(&self, name: &str) -> Option<Result<Filter, String>> {
+		self.smart_lists.iter()
+			.find(|(list, _)| list.split_whitespace().collect::<String>().eq_ignore_ascii_case(name))
+			.map(|(_, expression)| Filter::parse(expression))
 	}
 }
 
-impl From<String> for Command {
-	fn from(value: String) -> Self {
-		let value = value.as_str();
+impl PomodoroLog {
+	fn record(&mut self, day: NaiveDate) {
+		self.completed += 1;
 
-		match value {
-			"help" | "Help" | "HELP" => Command::Help,
-			"list" | "List" | "LIST" => Command::List,
-			"add" | "Add" | "ADD" => Command::Add,
-			"remove" | "Remove" | "REMOVE" => Command::Remove,
-			"clear" | "Clear" | "CLEAR" => Command::Clear,
-			"save" | "Save" | "SAVE" => Command::Save,
-			"load" | "Load" | "LOAD" => Command::Load,
-			"exit" | "Exit" | "EXIT" => Command::Exit,
-			_ => Command::Unknown
+		match self.last_day {
+			Some(last_day) if last_day == day => {}
+			Some(last_day) if last_day.succ_opt() == Some(day) => self.streak += 1,
+			_ => self.streak = 1,
 		}
+
+		self.best_streak = self.best_streak.max(self.streak);
+		self.last_day = Some(day);
 	}
 }
 
-impl CommandState {
-	fn empty() -> Self {
-		CommandState {
-			name: None,
-			description: None,
-			index: None
+impl TodoEntry {
+	fn new(name: String, description: String) -> Self {
+		TodoEntry {
+			name,
+			description,
+			time_log: Vec::new(),
+			tracking_since: None,
+			source: None,
+			history: Vec::new(),
+			id: 0,
+			links: Vec::new(),
+			estimate_minutes: None,
+			assignee: None,
+			location: None,
+			color: None,
 		}
 	}
 
-	fn add(name: String, description: String) -> Self {
-		CommandState {
-			name: Some(name),
-			description: Some(description),
-			index: None
+	fn estimate(&self) -> Option<chrono::Duration> {
+		self.estimate_minutes.map(chrono::Duration::minutes)
+	}
+
+	fn rename(&mut self, name: String) {
+		let old = std::mem::replace(&mut self.name, name);
+		self.record_change("name", old, self.name.clone());
+	}
+
+	fn describe(&mut self, description: String) {
+		let old = std::mem::replace(&mut self.description, description);
+		self.record_change("description", old, self.description.clone());
+	}
+
+	fn assign(&mut self, assignee: Option<String>) {
+		let old = std::mem::replace(&mut self.assignee, assignee);
+		self.record_change("assignee", old.unwrap_or_default(), self.assignee.clone().unwrap_or_default());
+	}
+
+	fn locate(&mut self, location: Option<String>) {
+		let old = std::mem::replace(&mut self.location, location);
+		self.record_change("location", old.unwrap_or_default(), self.location.clone().unwrap_or_default());
+	}
+
+	fn paint(&mut self, color: Option<Color>) {
+		let old = std::mem::replace(&mut self.color, color);
+		self.record_change(
+			"color",
+			old.map(Color::name).unwrap_or_default().to_string(),
+			self.color.map(Color::name).unwrap_or_default().to_string()
+		);
+	}
+
+	fn set_estimate(&mut self, estimate: chrono::Duration) {
+		let minutes = (!estimate.is_zero()).then(|| estimate.num_minutes().max(1));
+		let old = std::mem::replace(&mut self.estimate_minutes, minutes);
+		self.record_change(
+			"estimate",
+			old.map(|minutes| format_duration(chrono::Duration::minutes(minutes))).unwrap_or_default(),
+			self.estimate().map(format_duration).unwrap_or_default()
+		);
+	}
+
+	fn record_change(&mut self, field: &str, old: String, new: String) {
+		if old == new {
+			return;
 		}
+
+		self.history.push(EntryChange {
+			field: field.to_string(),
+			old,
+			new,
+			timestamp: now(),
+		});
+	}
+
+	fn assigned_to(&self, name: &str) -> bool {
+		self.assignee.as_deref().is_some_and(|assignee| assignee.eq_ignore_ascii_case(name))
 	}
 
-	fn remove(index: usize) -> Self {
-		CommandState {
-			name: None,
-			description: None,
-			index: Some(index)
+	fn is_named(&self, name: &str) -> bool {
+		let normalize = |name: &str| name.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase();
+
+		normalize(&self.name) == normalize(name)
+	}
+
+	fn located_at(&self, place: &str) -> bool {
+		self.location.as_deref().is_some_and(|location| location.eq_ignore_ascii_case(place))
+	}
+
+	fn matches(&self, filter: &str) -> bool {
+		self.name.to_lowercase().contains(filter) || self.description.to_lowercase().contains(filter)
+	}
+
+	fn intervals(&self, now: DateTime<Utc>) -> Vec<TimeInterval> {
+		let mut intervals = self.time_log.clone();
+
+		if let Some(start) = self.tracking_since {
+			intervals.push(TimeInterval { start, end: now });
+		}
+
+		intervals
+	}
+
+	fn tracked_time(&self, now: DateTime<Utc>) -> chrono::Duration {
+		self.intervals(now).iter().map(TimeInterval::duration).sum()
+	}
+
+	fn stop_tracking(&mut self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+		let start = self.tracking_since.take()?;
+		let interval = TimeInterval { start, end: now };
+		let duration = interval.duration();
+
+		self.time_log.push(interval);
+
+		Some(duration)
+	}
+}
+
+impl Color {
+	const ALL: [Color; 6] = [Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta, Color::Cyan];
+
+	fn parse(name: &str) -> Option<Self> {
+		Color::ALL.into_iter().find(|color| color.name().eq_ignore_ascii_case(name))
+	}
+
+	fn name(self) -> &'static str {
+		match self {
+			Color::Red => "red",
+			Color::Green => "green",
+			Color::Yellow => "yellow",
+			Color::Blue => "blue",
+			Color::Magenta => "magenta",
+			Color::Cyan => "cyan",
 		}
 	}
+
+	fn paint(self, text: &str) -> String {
+		if !colors_enabled() {
+			return text.to_string();
+		}
+
+		let code = match self {
+			Color::Red => 31,
+			Color::Green => 32,
+			Color::Yellow => 33,
+			Color::Blue => 34,
+			Color::Magenta => 35,
+			Color::Cyan => 36,
+		};
+
+		format!("\x1b[{code}m{text}\x1b[0m")
+	}
+}
+
+impl TimeInterval {
+	fn duration(&self) -> chrono::Duration {
+		self.end - self.start
+	}
+
+	fn day(&self, zone: Zone) -> NaiveDate {
+		zone.localize(self.start).date_naive()
+	}
+}
+
+fn colors_enabled() -> bool {
+	std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+fn parse_duration(value: &str) -> Option<chrono::Duration> {
+	let (amount, unit) = value.split_at(value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len()));
+	let amount = amount.parse::<i64>().ok()?;
+
+	match unit {
+		"s" => Some(chrono::Duration::seconds(amount)),
+		"" | "m" => Some(chrono::Duration::minutes(amount)),
+		"h" => Some(chrono::Duration::hours(amount)),
+		_ => None,
+	}
+}
+
+fn format_duration(duration: chrono::Duration) -> String {
+	let minutes = duration.num_minutes();
+
+	if minutes < 60 {
+		format!("{minutes}m")
+	} else {
+		format!("{}h {}m", minutes / 60, minutes % 60)
+	}
 }
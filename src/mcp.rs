@@ -0,0 +1,199 @@
+use std::io::{BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{write_state_file, State, TodoEntry};
+
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+#[derive(Deserialize)]
+struct RpcRequest {
+	#[serde(default)]
+	pub id: Option<Value>,
+	pub method: String,
+	#[serde(default)]
+	pub params: Value,
+}
+
+#[derive(Serialize)]
+struct RpcResponse {
+	pub jsonrpc: &'static str,
+	pub id: Value,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub result: Option<Value>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<RpcError>,
+}
+
+#[derive(Serialize)]
+struct RpcError {
+	pub code: i64,
+	pub message: String,
+}
+
+pub fn run(state: &mut State) {
+	let stdin = std::io::stdin();
+	let mut stdout = std::io::stdout();
+
+	for line in stdin.lock().lines().map_while(Result::ok) {
+		if line.trim().is_empty() {
+			continue;
+		}
+
+		let response = match serde_json::from_str::<RpcRequest>(&line) {
+			Ok(request) => {
+				let Some(id) = request.id else {
+					continue;
+				};
+
+				match handle(state, &request.method, &request.params) {
+					Ok(result) => RpcResponse::result(id, result),
+					Err(error) => RpcResponse::error(id, error),
+				}
+			}
+			Err(error) => RpcResponse::error(
+				Value::Null,
+				RpcError::new(-32700, format!("Parse error: {error}"))
+			),
+		};
+
+		let data = serde_json::to_string(&response).unwrap_or_default();
+
+		if writeln!(stdout, "{data}").and_then(|()| stdout.flush()).is_err() {
+			break;
+		}
+	}
+}
+
+fn handle(state: &mut State, method: &str, params: &Value) -> Result<Value, RpcError> {
+	match method {
+		"initialize" => Ok(json!({
+			"protocolVersion": PROTOCOL_VERSION,
+			"capabilities": { "tools": {} },
+			"serverInfo": {
+				"name": env!("CARGO_PKG_NAME"),
+				"version": env!("CARGO_PKG_VERSION"),
+			},
+		})),
+		"ping" => Ok(json!({})),
+		"tools/list" => Ok(json!({ "tools": tools() })),
+		"tools/call" => {
+			let name = params["name"].as_str().unwrap_or_default();
+			let arguments = &params["arguments"];
+
+			let (text, is_error) = match call_tool(state, name, arguments) {
+				Ok(text) => (text, false),
+				Err(text) => (text, true),
+			};
+
+			Ok(json!({
+				"content": [{ "type": "text", "text": text }],
+				"isError": is_error,
+			}))
+		}
+		_ => Err(RpcError::new(-32601, format!("Method not found: {method}"))),
+	}
+}
+
+fn tools() -> Value {
+	json!([
+		{
+			"name": "list_todos",
+			"description": "Lists all todo entries with their index, name and description",
+			"inputSchema": { "type": "object", "properties": {} },
+		},
+		{
+			"name": "add_todo",
+			"description": "Adds a new todo entry",
+			"inputSchema": {
+				"type": "object",
+				"properties": {
+					"name": { "type": "string", "description": "Name of the todo entry" },
+					"description": { "type": "string", "description": "Description of the todo entry" },
+				},
+				"required": ["name"],
+			},
+		},
+		{
+			"name": "remove_todo",
+			"description": "Removes a todo entry by its index",
+			"inputSchema": {
+				"type": "object",
+				"properties": {
+					"index": { "type": "integer", "description": "Index of the todo entry to remove" },
+				},
+				"required": ["index"],
+			},
+		},
+	])
+}
+
+fn call_tool(state: &mut State, name: &str, arguments: &Value) -> Result<String, String> {
+	match name {
+		"list_todos" => {
+			if state.entries.is_empty() {
+				return Ok("Nothing to list".to_string());
+			}
+
+			Ok(
+				state.entries.iter()
+					.enumerate()
+					.map(|(index, entry)| format!("{index} - {}: {}", entry.name, entry.description))
+					.collect::<Vec<String>>()
+					.join("\n")
+			)
+		}
+		"add_todo" => {
+			let Some(name) = arguments["name"].as_str() else {
+				return Err("add_todo requires a name".to_string());
+			};
+			let description = arguments["description"].as_str().unwrap_or_default();
+
+			let index = state.add_entry(TodoEntry::new(name.to_string(), description.to_string()));
+			write_state_file(state);
+
+			Ok(format!("Added entry {name} at index {index}"))
+		}
+		"remove_todo" => {
+			let entry = arguments["index"].as_u64()
+				.and_then(|index| usize::try_from(index).ok())
+				.and_then(|index| state.remove_entry(index))
+				.ok_or_else(|| format!("No todo entry found at index {}", arguments["index"]))?;
+
+			write_state_file(state);
+
+			Ok(format!("Removed entry {}", entry.name))
+		}
+		_ => Err(format!("Unknown tool: {name}")),
+	}
+}
+
+impl RpcResponse {
+	fn result(id: Value, result: Value) -> Self {
+		RpcResponse {
+			jsonrpc: "2.0",
+			id,
+			result: Some(result),
+			error: None,
+		}
+	}
+
+	fn error(id: Value, error: RpcError) -> Self {
+		RpcResponse {
+			jsonrpc: "2.0",
+			id,
+			result: None,
+			error: Some(error),
+		}
+	}
+}
+
+impl RpcError {
+	fn new(code: i64, message: String) -> Self {
+		RpcError {
+			code,
+			message,
+		}
+	}
+}
@@ -0,0 +1,29 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+macro_rules! out {
+	($($argument:tt)*) => {
+		if !$crate::output::is_quiet() {
+			print!($($argument)*);
+		}
+	};
+}
+
+macro_rules! outln {
+	($($argument:tt)*) => {
+		if !$crate::output::is_quiet() {
+			println!($($argument)*);
+		}
+	};
+}
+
+pub(crate) use {out, outln};
+
+pub fn silence() {
+	QUIET.store(true, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+	QUIET.load(Ordering::Relaxed)
+}
@@ -0,0 +1,44 @@
+use chrono::Duration;
+
+use crate::locale::tr;
+use crate::{Color, EntryLink, State, TimeInterval, TodoEntry, DEFAULT_LINK_RELATION};
+
+pub fn populate(state: &mut State) {
+	let now = crate::now();
+
+	let mut report = TodoEntry::new(tr!("sandbox-report"), tr!("sandbox-report-description"));
+	report.estimate_minutes = Some(90);
+	report.assignee = Some("Alice".to_string());
+	report.color = Some(Color::Red);
+	report.time_log.push(TimeInterval {
+		start: now - Duration::days(1) - Duration::minutes(45),
+		end: now - Duration::days(1),
+	});
+
+	let mut groceries = TodoEntry::new(tr!("sandbox-groceries"), tr!("sandbox-groceries-description"));
+	groceries.location = Some(tr!("sandbox-supermarket"));
+	groceries.color = Some(Color::Green);
+
+	let mut dentist = TodoEntry::new(tr!("sandbox-dentist"), String::new());
+	dentist.estimate_minutes = Some(10);
+
+	let mut bike = TodoEntry::new(tr!("sandbox-bike"), String::new());
+	bike.location = Some(tr!("sandbox-garage"));
+	bike.assignee = Some("Bob".to_string());
+
+	let trip = TodoEntry::new(tr!("sandbox-trip"), tr!("sandbox-trip-description"));
+
+	for entry in [report, groceries, dentist, bike] {
+		state.add_entry(entry);
+	}
+
+	let groceries = state.entries[1].id;
+	let trip = state.add_entry(trip);
+
+	state.entries[trip].links.push(EntryLink {
+		target: groceries,
+		relation: DEFAULT_LINK_RELATION.to_string(),
+	});
+
+	state.modified = false;
+}
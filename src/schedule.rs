@@ -0,0 +1,206 @@
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, DurationRound, FixedOffset, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::locale::tr;
+use crate::output::outln;
+use crate::timezone::Zone;
+use crate::{run_line, State, INTERACTIVE};
+
+const SEARCH_LIMIT_MINUTES: i64 = 366 * 24 * 60;
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduledCommand {
+	pub cron: String,
+	pub command: String,
+}
+
+pub struct Cron {
+	minutes: u64,
+	hours: u64,
+	days: u64,
+	months: u64,
+	weekdays: u64,
+	any_day: bool,
+	any_weekday: bool,
+}
+
+impl Cron {
+	pub fn parse(expression: &str) -> Result<Self, String> {
+		let fields = expression.split_whitespace().collect::<Vec<&str>>();
+
+		let [minutes, hours, days, months, weekdays] = fields[..] else {
+			return Err(tr!("cron-field-count", expression = expression));
+		};
+
+		let weekdays_mask = field(weekdays, 0, 7)?;
+
+		Ok(Cron {
+			minutes: field(minutes, 0, 59)?,
+			hours: field(hours, 0, 23)?,
+			days: field(days, 1, 31)?,
+			months: field(months, 1, 12)?,
+			weekdays: (weekdays_mask | weekdays_mask >> 7) & 0x7f,
+			any_day: days == "*",
+			any_weekday: weekdays == "*",
+		})
+	}
+
+	pub fn matches(&self, time: &DateTime<FixedOffset>) -> bool {
+		let day = self.days & 1 << time.day() != 0;
+		let weekday = self.weekdays & 1 << time.weekday().num_days_from_sunday() != 0;
+
+		let day_matches = match (self.any_day, self.any_weekday) {
+			(true, true) => true,
+			(true, false) => weekday,
+			(false, true) => day,
+			(false, false) => day || weekday,
+		};
+
+		self.minutes & 1 << time.minute() != 0
+			&& self.hours & 1 << time.hour() != 0
+			&& self.months & 1 << time.month() != 0
+			&& day_matches
+	}
+
+	pub fn next_after(&self, after: DateTime<Utc>, zone: Zone) -> Option<DateTime<Utc>> {
+		let start = after.duration_trunc(chrono::Duration::minutes(1)).ok()?;
+
+		(1..=SEARCH_LIMIT_MINUTES)
+			.map(|minutes| start + chrono::Duration::minutes(minutes))
+			.find(|time| self.matches(&zone.localize(*time)))
+	}
+}
+
+fn field(spec: &str, min: u32, max: u32) -> Result<u64, String> {
+	let mut mask = 0;
+
+	for part in spec.split(',') {
+		let (range, step) = match part.split_once('/') {
+			Some((range, step)) => (range, step.parse::<usize>().ok().filter(|step| *step > 0)),
+			None => (part, Some(1)),
+		};
+
+		let Some(step) = step else {
+			return Err(tr!("cron-invalid-field", field = spec));
+		};
+
+		let bounds = match range.split_once('-') {
+			_ if range == "*" => Some((min, max)),
+			Some((start, end)) => start.parse::<u32>().ok().zip(end.parse::<u32>().ok()),
+			None => range.parse::<u32>().ok().map(|start| (start, if part.contains('/') { max } else { start })),
+		};
+
+		match bounds {
+			Some((start, end)) if min <= start && start <= end && end <= max => {
+				for value in (start..=end).step_by(step) {
+					mask |= 1 << value;
+				}
+			}
+			_ => return Err(tr!("cron-invalid-field", field = spec)),
+		}
+	}
+
+	Ok(mask)
+}
+
+pub fn run(state: Arc<Mutex<State>>) {
+	if state.lock().unwrap_or_else(PoisonError::into_inner).config.schedules.is_empty() {
+		return;
+	}
+
+	std::thread::spawn(move || {
+		INTERACTIVE.set(false);
+
+		loop {
+			let now = Utc::now();
+			let next_minute = 60 - u64::from(now.second());
+			std::thread::sleep(Duration::from_secs(next_minute));
+
+			let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+
+			if state.exit {
+				break;
+			}
+
+			let time = state.config.zone().localize(Utc::now());
+			let due = state.config.schedules.iter()
+				.filter(|schedule| Cron::parse(&schedule.cron).is_ok_and(|cron| cron.matches(&time)))
+				.map(|schedule| schedule.command.clone())
+				.collect::<Vec<String>>();
+
+			for command in due {
+				outln!("{}", tr!("schedule-running", command = command.as_str()));
+				tracing::info!("Running scheduled command {command:?}");
+				run_line(&mut state, &command).unwrap_or_default();
+			}
+		}
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{DateTime, FixedOffset, TimeZone};
+
+	use super::Cron;
+
+	fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<FixedOffset> {
+		FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap()
+	}
+
+	#[test]
+	fn steps_match_every_nth_value() {
+		let cron = Cron::parse("*/15 * * * *").unwrap();
+
+		assert!(cron.matches(&at(2024, 1, 1, 9, 0)));
+		assert!(cron.matches(&at(2024, 1, 1, 9, 45)));
+		assert!(!cron.matches(&at(2024, 1, 1, 9, 10)));
+
+		let cron = Cron::parse("5/20 * * * *").unwrap();
+
+		assert!(cron.matches(&at(2024, 1, 1, 9, 25)));
+		assert!(!cron.matches(&at(2024, 1, 1, 9, 20)));
+	}
+
+	#[test]
+	fn ranges_are_inclusive() {
+		let cron = Cron::parse("0 9-17 * * 1-5").unwrap();
+
+		assert!(cron.matches(&at(2024, 1, 1, 9, 0)));
+		assert!(cron.matches(&at(2024, 1, 5, 17, 0)));
+		assert!(!cron.matches(&at(2024, 1, 1, 18, 0)));
+		assert!(!cron.matches(&at(2024, 1, 6, 9, 0)));
+	}
+
+	#[test]
+	fn lists_combine_values_and_ranges() {
+		let cron = Cron::parse("0,30 8,12-13 * * *").unwrap();
+
+		assert!(cron.matches(&at(2024, 1, 1, 8, 30)));
+		assert!(cron.matches(&at(2024, 1, 1, 13, 0)));
+		assert!(!cron.matches(&at(2024, 1, 1, 10, 0)));
+		assert!(!cron.matches(&at(2024, 1, 1, 8, 15)));
+	}
+
+	#[test]
+	fn weekday_seven_is_sunday() {
+		let sunday = at(2024, 1, 7, 10, 0);
+
+		assert!(Cron::parse("0 10 * * 7").unwrap().matches(&sunday));
+		assert!(Cron::parse("0 10 * * 0").unwrap().matches(&sunday));
+		assert!(Cron::parse("0 10 * * 6-7").unwrap().matches(&sunday));
+		assert!(!Cron::parse("0 10 * * 7").unwrap().matches(&at(2024, 1, 8, 10, 0)));
+	}
+
+	#[test]
+	fn invalid_expressions_are_rejected() {
+		assert!(Cron::parse("* * * *").is_err());
+		assert!(Cron::parse("60 * * * *").is_err());
+		assert!(Cron::parse("*/0 * * * *").is_err());
+		assert!(Cron::parse("5-1 * * * *").is_err());
+		assert!(Cron::parse("* * * * 8").is_err());
+	}
+}
@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rhai::{Array, Dynamic, Engine, Map};
+
+use crate::{State, TodoEntry};
+
+const MAX_OPERATIONS: u64 = 10_000_000;
+
+pub fn run(state: &mut State, source: &str) -> Result<(), String> {
+	let original = state.clone();
+	let shared = Rc::new(RefCell::new(std::mem::replace(state, State::new())));
+
+	let result = {
+		let engine = engine(&shared);
+		engine.run(source).map_err(|error| error.to_string())
+	};
+
+	*state = Rc::try_unwrap(shared)
+		.map(RefCell::into_inner)
+		.unwrap_or_else(|_| original.clone());
+
+	if result.is_err() {
+		*state = original;
+	}
+
+	result
+}
+
+fn engine(shared: &Rc<RefCell<State>>) -> Engine {
+	let mut engine = Engine::new();
+	engine.set_max_operations(MAX_OPERATIONS);
+
+	let state = Rc::clone(shared);
+	engine.register_fn("entries", move || -> Array {
+		state.borrow().entries.iter()
+			.enumerate()
+			.map(|(index, entry)| {
+				let mut map = Map::new();
+				map.insert("index".into(), Dynamic::from(index as i64));
+				map.insert("name".into(), entry.name.clone().into());
+				map.insert("description".into(), entry.description.clone().into());
+				Dynamic::from_map(map)
+			})
+			.collect()
+	});
+
+	let state = Rc::clone(shared);
+	engine.register_fn("count", move || -> i64 {
+		state.borrow().entries.len() as i64
+	});
+
+	let state = Rc::clone(shared);
+	engine.register_fn("add", move |name: &str, description: &str| -> i64 {
+		state.borrow_mut().add_entry(TodoEntry::new(name.to_string(), description.to_string())) as i64
+	});
+
+	let state = Rc::clone(shared);
+	engine.register_fn("remove", move |index: i64| -> bool {
+		usize::try_from(index).ok()
+			.and_then(|index| state.borrow_mut().remove_entry(index))
+			.is_some()
+	});
+
+	let state = Rc::clone(shared);
+	engine.register_fn("rename", move |index: i64, name: &str| -> bool {
+		with_entry(&state, index, |entry| entry.rename(name.to_string()))
+	});
+
+	let state = Rc::clone(shared);
+	engine.register_fn("describe", move |index: i64, description: &str| -> bool {
+		with_entry(&state, index, |entry| entry.describe(description.to_string()))
+	});
+
+	engine
+}
+
+fn with_entry(state: &RefCell<State>, index: i64, update: impl FnOnce(&mut TodoEntry)) -> bool {
+	let mut state = state.borrow_mut();
+
+	match usize::try_from(index).ok().and_then(|index| state.entries.get_mut(index)) {
+		Some(entry) => {
+			update(entry);
+			true
+		}
+		None => false,
+	}
+}
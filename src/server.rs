@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Method, ReadWrite, Request, Response, Server, StatusCode};
+use tungstenite::handshake::derive_accept_key;
+use tungstenite::protocol::Role;
+use tungstenite::{Message, WebSocket};
+
+use crate::export::html_escape;
+use crate::locale::{self, tr};
+use crate::output::outln;
+use crate::{write_state_file, State, TodoEntry};
+
+const INDEX_HTML: &str = include_str!("web/index.html");
+
+#[derive(Serialize)]
+struct EntryView<'a> {
+	pub index: usize,
+	#[serde(flatten)]
+	pub entry: &'a TodoEntry,
+}
+
+#[derive(Deserialize)]
+struct NewEntry {
+	pub name: String,
+	#[serde(default)]
+	pub description: String,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+	pub error: String,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "event", content = "entry", rename_all = "snake_case")]
+enum EntryEvent<'a> {
+	Added(EntryView<'a>),
+	Removed(EntryView<'a>),
+}
+
+struct Reply {
+	status: u16,
+	body: String,
+	content_type: &'static str,
+	event: Option<String>,
+}
+
+type Subscriber = WebSocket<Box<dyn ReadWrite + Send>>;
+
+pub fn serve(state: &mut State, host: &str, port: u16) {
+	let server = match Server::http((host, port)) {
+		Ok(server) => server,
+		Err(error) => {
+			eprintln!("{}", tr!("server-start-failed", port = port, error = error.to_string()));
+			return;
+		}
+	};
+
+	outln!("{}", tr!("server-started", url = format!("http://{host}:{port}")));
+
+	let mut subscribers = Vec::<Subscriber>::new();
+
+	for mut request in server.incoming_requests() {
+		if route(request.url()) == "/events" {
+			if let Some(subscriber) = subscribe(request) {
+				subscribers.push(subscriber);
+			}
+
+			continue;
+		}
+
+		let reply = handle(state, &mut request);
+
+		if let Some(event) = &reply.event {
+			write_state_file(state);
+
+			subscribers.retain_mut(|subscriber| {
+				subscriber.send(Message::text(event.as_str())).is_ok()
+			});
+		}
+
+		let response = Response::from_string(reply.body)
+			.with_status_code(reply.status)
+			.with_header(
+				Header::from_bytes("Content-Type", reply.content_type)
+					.expect("static header is valid")
+			);
+
+		request.respond(response).unwrap_or_else(|_| {
+			eprintln!("{}", tr!("server-response-failed"));
+		});
+	}
+}
+
+fn subscribe(request: Request) -> Option<Subscriber> {
+	let key = request.headers().iter()
+		.find(|header| header.field.equiv("Sec-WebSocket-Key"))
+		.map(|header| header.value.to_string());
+
+	let Some(key) = key else {
+		let response = Response::from_string("Expected a WebSocket upgrade request")
+			.with_status_code(400);
+
+		request.respond(response).unwrap_or_else(|_| {
+			eprintln!("{}", tr!("server-response-failed"));
+		});
+
+		return None;
+	};
+
+	let response = Response::empty(StatusCode(101)).with_header(
+		Header::from_bytes("Sec-WebSocket-Accept", derive_accept_key(key.as_bytes()))
+			.expect("accept key is a valid header value")
+	);
+
+	let stream = request.upgrade("websocket", response);
+
+	Some(WebSocket::from_raw_socket(stream, Role::Server, None))
+}
+
+fn handle(state: &mut State, request: &mut Request) -> Reply {
+	let path = route(request.url()).trim_end_matches('/').to_string();
+	let segments = path.split('/').skip(1).collect::<Vec<&str>>();
+
+	match (request.method(), segments.as_slice()) {
+		(Method::Get, []) => Reply::html(&index_html()),
+		(Method::Get, ["entries"]) => {
+			let entries = state.entries.iter()
+				.enumerate()
+				.map(|(index, entry)| EntryView { index, entry })
+				.collect::<Vec<EntryView>>();
+
+			Reply::json(200, &entries)
+		}
+		(Method::Get, ["entries", index]) => {
+			match index.parse::<usize>().ok().and_then(|index| Some((index, state.entries.get(index)?))) {
+				Some((index, entry)) => Reply::json(200, &EntryView { index, entry }),
+				None => Reply::not_found(index),
+			}
+		}
+		(Method::Post, ["entries"]) => {
+			let mut body = String::new();
+
+			if request.as_reader().read_to_string(&mut body).is_err() {
+				return Reply::error(400, "Failed to read request body");
+			}
+
+			match serde_json::from_str::<NewEntry>(&body) {
+				Ok(new_entry) if new_entry.name.trim().is_empty() => {
+					Reply::error(400, "The name of an entry cannot be empty")
+				}
+				Ok(new_entry) => {
+					let index = state.add_entry(TodoEntry::new(new_entry.name, new_entry.description));
+					let entry = &state.entries[index];
+
+					Reply::json(201, &EntryView { index, entry })
+						.with_event(&EntryEvent::Added(EntryView { index, entry }))
+				}
+				Err(parse_error) => Reply::error(400, &format!("Invalid entry: {parse_error}")),
+			}
+		}
+		(Method::Delete, ["entries", index]) => {
+			match index.parse::<usize>().ok().and_then(|index| Some((index, state.remove_entry(index)?))) {
+				Some((index, entry)) => {
+					Reply::json(200, &EntryView { index, entry: &entry })
+						.with_event(&EntryEvent::Removed(EntryView { index, entry: &entry }))
+				}
+				None => Reply::not_found(index),
+			}
+		}
+		(_, ["entries", ..]) => Reply::error(405, "Method not allowed"),
+		_ => Reply::error(404, "Not found"),
+	}
+}
+
+fn route(url: &str) -> &str {
+	url.split_once('?').map_or(url, |(path, _)| path)
+}
+
+impl Reply {
+	fn json<T: Serialize>(status: u16, value: &T) -> Self {
+		Reply {
+			status,
+			body: to_json(value),
+			content_type: "application/json",
+			event: None,
+		}
+	}
+
+	fn html(body: &str) -> Self {
+		Reply {
+			status: 200,
+			body: body.to_string(),
+			content_type: "text/html; charset=utf-8",
+			event: None,
+		}
+	}
+
+	fn error(status: u16, message: &str) -> Self {
+		Reply::json(status, &ErrorResponse { error: message.to_string() })
+	}
+
+	fn not_found(index: &str) -> Self {
+		Reply::error(404, &format!("No todo entry found at index {index}"))
+	}
+
+	fn with_event(self, event: &EntryEvent) -> Self {
+		Reply {
+			event: Some(to_json(event)),
+			..self
+		}
+	}
+}
+
+fn index_html() -> String {
+	INDEX_HTML
+		.replace("{{language}}", &locale::language())
+		.replace("{{title}}", &html_escape(&tr!("title")))
+		.replace("{{name}}", &html_escape(&tr!("web-name")))
+		.replace("{{description}}", &html_escape(&tr!("web-description")))
+		.replace("{{add}}", &html_escape(&tr!("web-add")))
+		.replace("{{remove}}", &to_json(&tr!("web-remove")))
+		.replace("{{nothing-to-list}}", &to_json(&tr!("nothing-to-list")))
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+	serde_json::to_string(value).unwrap_or_else(|_| {
+		eprintln!("{}", tr!("server-serialize-failed"));
+		String::new()
+	})
+}
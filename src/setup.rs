@@ -0,0 +1,44 @@
+use crate::locale::{self, tr};
+use crate::output::outln;
+use crate::timezone::Zone;
+use crate::{argument_or_prompt, confirm, parse_duration, write_config_file, State, CONFIG_FILE_PATH};
+
+pub fn run(state: &mut State) {
+	outln!("{}", tr!("setup-welcome", path = CONFIG_FILE_PATH));
+
+	let locales = locale::supported();
+
+	state.config.locale = ask(
+		&tr!("setup-locale", locales = locales.join(", ")),
+		|value| locales.contains(&value),
+	);
+	state.config.timezone = ask(&tr!("setup-timezone"), |value| Zone::parse(Some(value)).is_ok());
+	state.config.twelve_hour_clock = confirm(&tr!("setup-twelve-hour-clock"));
+	state.config.daily_capacity = ask(&tr!("setup-daily-capacity"), |value| parse_duration(value).is_some());
+	state.config.git_sync = confirm(&tr!("setup-git-sync"));
+
+	if write_config_file(state) {
+		outln!("{}", tr!("setup-finished", path = CONFIG_FILE_PATH));
+
+		if state.config.locale.is_some() {
+			outln!("{}", tr!("setup-locale-restart"));
+		}
+	}
+}
+
+fn ask(prompt: &str, valid: impl Fn(&str) -> bool) -> Option<String> {
+	loop {
+		let answer = argument_or_prompt(None, prompt);
+		let answer = answer.trim();
+
+		if answer.is_empty() {
+			return None;
+		}
+
+		if valid(answer) {
+			return Some(answer.to_string());
+		}
+
+		eprintln!("{}", tr!("unknown-input"));
+	}
+}
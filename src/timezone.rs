@@ -0,0 +1,71 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+#[derive(Clone, Copy)]
+pub enum Zone {
+	System,
+	Named(Tz),
+}
+
+impl Zone {
+	pub fn parse(name: Option<&str>) -> Result<Self, String> {
+		match name {
+			Some(name) => name.parse::<Tz>().map(Zone::Named).map_err(|_| name.to_string()),
+			None => Ok(Zone::System),
+		}
+	}
+
+	pub fn localize(self, timestamp: DateTime<Utc>) -> DateTime<FixedOffset> {
+		let offset = match self {
+			Zone::System if crate::is_deterministic() => Utc.fix(),
+			Zone::System => Local.offset_from_utc_datetime(&timestamp.naive_utc()).fix(),
+			Zone::Named(zone) => zone.offset_from_utc_datetime(&timestamp.naive_utc()).fix(),
+		};
+
+		timestamp.with_timezone(&offset)
+	}
+
+	pub fn today(self) -> NaiveDate {
+		self.localize(crate::now()).date_naive()
+	}
+
+	pub fn to_utc(self, local: &NaiveDateTime) -> Option<DateTime<Utc>> {
+		match self {
+			Zone::System if crate::is_deterministic() => Some(Utc.from_utc_datetime(local)),
+			Zone::System => Local.from_local_datetime(local).earliest().map(|time| time.with_timezone(&Utc)),
+			Zone::Named(zone) => zone.from_local_datetime(local).earliest().map(|time| time.with_timezone(&Utc)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{DateTime, Duration};
+
+	use super::Zone;
+	use crate::export::{self, Field, Selection};
+	use crate::{import, State, TimeInterval, TodoEntry, SEED};
+
+	#[test]
+	fn org_clock_round_trip_is_deterministic() {
+		std::env::set_var("TZ", "America/New_York");
+		SEED.get_or_init(|| 0);
+
+		let start = DateTime::from_timestamp(1_699_999_980, 0).unwrap();
+		let mut entry = TodoEntry::new("Report".to_string(), String::new());
+		entry.time_log.push(TimeInterval {
+			start,
+			end: start + Duration::minutes(90),
+		});
+
+		let state = State::new();
+		let selection = Selection {
+			entries: vec![(0, &entry)],
+			fields: vec![Field::Name, Field::Tracked],
+		};
+
+		let imported = import::org(&export::org(&state, &selection), Zone::System);
+
+		assert!(imported.entries[0].time_log == entry.time_log);
+	}
+}
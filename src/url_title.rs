@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::locale::tr;
+use crate::output::outln;
+
+const FETCH_TIMEOUT_SECONDS: u64 = 5;
+
+pub fn apply(name: String, description: String) -> (String, String) {
+	let url = match (name.trim(), description.trim()) {
+		("", url) | (url, "") if is_url(url) => url.to_string(),
+		_ => return (name, description),
+	};
+
+	match fetch(&url) {
+		Some(title) => {
+			outln!("{}", tr!("url-title-fetched", title = title.as_str()));
+			(title, url)
+		}
+		None => {
+			eprintln!("{}", tr!("url-title-failed", url = url.as_str()));
+			(url.clone(), url)
+		}
+	}
+}
+
+fn is_url(value: &str) -> bool {
+	(value.starts_with("https://") || value.starts_with("http://")) && !value.contains(char::is_whitespace)
+}
+
+fn fetch(url: &str) -> Option<String> {
+	let page = ureq::get(url)
+		.timeout(Duration::from_secs(FETCH_TIMEOUT_SECONDS))
+		.call()
+		.map_err(|error| error.to_string())
+		.and_then(|response| response.into_string().map_err(|error| error.to_string()))
+		.map_err(|error| tracing::warn!("Failed to fetch {url}: {error}"))
+		.ok()?;
+
+	let title = Regex::new(r"(?is)<title[^>]*>(.*?)</title>").ok()?
+		.captures(&page)?
+		.get(1)?
+		.as_str()
+		.split_whitespace()
+		.collect::<Vec<&str>>()
+		.join(" ")
+		.replace("&lt;", "<")
+		.replace("&gt;", ">")
+		.replace("&quot;", "\"")
+		.replace("&#39;", "'")
+		.replace("&amp;", "&");
+
+	Some(title).filter(|title| !title.is_empty())
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::locale::tr;
+use crate::output::outln;
+use crate::{read_state_file, State, STATE_FILE_PATH};
+
+const DEBOUNCE_MILLISECONDS: u64 = 200;
+
+pub fn watch(state: Arc<Mutex<State>>) -> Option<RecommendedWatcher> {
+	let (sender, receiver) = channel();
+
+	let mut watcher = notify::recommended_watcher(sender).map_err(|error| {
+		tracing::warn!("Failed to create file watcher: {error}");
+	}).ok()?;
+
+	watcher.watch(Path::new("."), RecursiveMode::NonRecursive).map_err(|error| {
+		tracing::warn!("Failed to watch {STATE_FILE_PATH}: {error}");
+	}).ok()?;
+
+	std::thread::spawn(move || {
+		while let Ok(event) = receiver.recv() {
+			if !is_state_file_change(event) {
+				continue;
+			}
+
+			loop {
+				match receiver.recv_timeout(Duration::from_millis(DEBOUNCE_MILLISECONDS)) {
+					Ok(_) => {}
+					Err(RecvTimeoutError::Timeout) => break,
+					Err(RecvTimeoutError::Disconnected) => return,
+				}
+			}
+
+			let Some(saved) = read_state_file() else {
+				continue;
+			};
+
+			let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+
+			if saved.entries != state.entries {
+				state.stale = true;
+				tracing::info!("{STATE_FILE_PATH} changed on disk");
+				outln!("{}", tr!("state-changed-on-disk", path = STATE_FILE_PATH));
+			}
+		}
+	});
+
+	Some(watcher)
+}
+
+fn is_state_file_change(event: notify::Result<notify::Event>) -> bool {
+	let Ok(event) = event else {
+		return false;
+	};
+
+	matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+		&& event.paths.iter().any(|path| path.file_name().is_some_and(|name| name == STATE_FILE_PATH))
+}
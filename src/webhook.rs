@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::locale::tr;
+use crate::TodoEntry;
+
+const WEBHOOK_TIMEOUT_SECONDS: u64 = 5;
+
+pub fn send(urls: &[String], event: &str, index: usize, entry: &TodoEntry) {
+	if urls.is_empty() {
+		return;
+	}
+
+	let payload = json!({
+		"event": event,
+		"index": index,
+		"entry": entry,
+		"timestamp": crate::now(),
+	});
+
+	for url in urls {
+		let result = ureq::post(url)
+			.timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECONDS))
+			.send_json(&payload);
+
+		match result {
+			Ok(_) => tracing::info!("Delivered {event} webhook to {url}"),
+			Err(error) => eprintln!("{}", tr!("webhook-failed", event = event, error = error.to_string())),
+		}
+	}
+}